@@ -1,500 +1,667 @@
-/// This is the `struct_to_string` crate.
-///
-/// This crate provides a procedural macro to convert struct definitions
-/// into a string representation. An example use case would be for
-/// API documentation where you want to present the Rust structs for the
-/// API response on a webpage.
-extern crate proc_macro;
-use proc_macro::TokenStream;
-use quote::quote;
-use quote::ToTokens;
-use syn::{parse_macro_input, DeriveInput, Type};
-
-/// The `StructToString` macro derives a `to_string` function for the struct.
+//! This is the `struct_to_string` crate.
+//!
+//! This crate provides a procedural macro to convert struct definitions
+//! into a string representation. An example use case would be for
+//! API documentation where you want to present the Rust structs for the
+//! API response on a webpage.
+pub use struct_to_string_derive::{struct_to_string_manifest, StructToString};
+
+/// The languages a derived type can be written in, for picking the output at runtime with
+/// the generated `to_string_in` method. Each variant is only available with the Cargo feature
+/// of the same name in lowercase, e.g. `typescript`, all of which are enabled by default.
 ///
-/// # Example
 /// ```
-/// use struct_to_string::StructToString;
+/// use struct_to_string::{Language, StructToString};
 ///
 /// #[derive(StructToString)]
-/// struct MyStruct {
-///     field1: i32,
-///     field2: String,
+/// struct User {
+///     name: String,
 /// }
 ///
-/// let my_struct_as_rust_string = MyStruct::to_rust_string();
-///
-/// // Struct to string can also be used to convert structs to other programming languages,
-/// // including Python, TypeScript, Go, Java, and C#.
-///
-/// let my_struct_as_c_sharp_string = MyStruct::to_csharp_string();
+/// for language in Language::ALL {
+///     assert!(!User::to_string_in(language).is_empty());
+/// }
 /// ```
-#[proc_macro_derive(StructToString)]
-pub fn struct_to_string(input: TokenStream) -> TokenStream {
-    let ast: DeriveInput = parse_macro_input!(input);
-    let name = ast.ident;
-
-    let mut rust_fields = String::new();
-    let mut go_fields = String::new();
-    let mut python_fields = String::new();
-    let mut ts_fields = String::new();
-    let mut java_fields = String::new();
-    let mut csharp_fields = String::new();
-
-    if let syn::Data::Struct(data_struct) = ast.data {
-        for field in data_struct.fields {
-            let field_name = field.ident.expect("Field name not found");
-            let field_type = field.ty;
-            let field_type_tokens = quote! { #field_type }.to_string().replace(" ", "");
-
-            let is_optional = match &field_type {
-                Type::Path(type_path) => {
-                    let last_segment = &type_path.path.segments.last().unwrap().ident;
-                    last_segment == "Option"
-                }
-                _ => false,
-            };
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Language {
+    #[cfg(feature = "rust")]
+    Rust,
+    #[cfg(feature = "typescript")]
+    TypeScript,
+    #[cfg(feature = "python")]
+    Python,
+    #[cfg(feature = "go")]
+    Go,
+    #[cfg(feature = "java")]
+    Java,
+    #[cfg(feature = "csharp")]
+    CSharp,
+    #[cfg(feature = "kotlin")]
+    Kotlin,
+    #[cfg(feature = "swift")]
+    Swift,
+    #[cfg(feature = "cpp")]
+    Cpp,
+    #[cfg(feature = "flow")]
+    Flow,
+    #[cfg(feature = "elixir")]
+    Elixir,
+    #[cfg(feature = "ocaml")]
+    OCaml,
+    #[cfg(feature = "fsharp")]
+    FSharp,
+}
 
-            // Rust representation
-            rust_fields.push_str(&format!("    {}: {},\n", field_name, field_type_tokens));
+impl Language {
+    /// Every supported language whose Cargo feature is enabled.
+    pub const ALL: [Language; Language::COUNT] = [
+        #[cfg(feature = "rust")]
+        Language::Rust,
+        #[cfg(feature = "typescript")]
+        Language::TypeScript,
+        #[cfg(feature = "python")]
+        Language::Python,
+        #[cfg(feature = "go")]
+        Language::Go,
+        #[cfg(feature = "java")]
+        Language::Java,
+        #[cfg(feature = "csharp")]
+        Language::CSharp,
+        #[cfg(feature = "kotlin")]
+        Language::Kotlin,
+        #[cfg(feature = "swift")]
+        Language::Swift,
+        #[cfg(feature = "cpp")]
+        Language::Cpp,
+        #[cfg(feature = "flow")]
+        Language::Flow,
+        #[cfg(feature = "elixir")]
+        Language::Elixir,
+        #[cfg(feature = "ocaml")]
+        Language::OCaml,
+        #[cfg(feature = "fsharp")]
+        Language::FSharp,
+    ];
 
-            // Go representation
-            let ts_field_name = if is_optional {
-                format!("{}?", field_name)
-            } else {
-                format!("{}", field_name)
-            };
+    const COUNT: usize = cfg!(feature = "rust") as usize
+        + cfg!(feature = "typescript") as usize
+        + cfg!(feature = "python") as usize
+        + cfg!(feature = "go") as usize
+        + cfg!(feature = "java") as usize
+        + cfg!(feature = "csharp") as usize
+        + cfg!(feature = "kotlin") as usize
+        + cfg!(feature = "swift") as usize
+        + cfg!(feature = "cpp") as usize
+        + cfg!(feature = "flow") as usize
+        + cfg!(feature = "elixir") as usize
+        + cfg!(feature = "ocaml") as usize
+        + cfg!(feature = "fsharp") as usize;
+}
 
-            // TypeScript representation
-            ts_fields.push_str(&format!(
-                "    {}: {};\n",
-                ts_field_name,
-                rust_type_to_ts_type(&field_type)
-            ));
-
-            // Python representation
-            python_fields.push_str(&format!(
-                "    {}: {}\n",
-                field_name,
-                rust_type_to_python_type(&field_type)
-            ));
-
-            // Go representation
-            go_fields.push_str(&format!(
-                "    {} {}\n",
-                field_name,
-                rust_type_to_go_type(&field_type)
-            ));
-
-            java_fields.push_str(&format!(
-                "    {} {} {};\n",
-                "public",
-                rust_type_to_java_type(&field_type),
-                field_name,
-            ));
-
-            csharp_fields.push_str(&format!(
-                "    {} {} {};\n",
-                "public",
-                rust_type_to_csharp_type(&field_type),
-                field_name,
-            ));
+/// The Java collection type `Vec<T>` fields are declared as, picked with
+/// `StructToStringBuilder::java_collection`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum JavaCollection {
+    #[default]
+    List,
+    Stream,
+    Iterable,
+}
+
+/// How the TypeScript output types `Result<T, E>` fields, picked with
+/// `StructToStringBuilder::result`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ResultStrategy {
+    /// `{ Ok: T } | { Err: E }`, the object serde writes by default.
+    #[default]
+    Tagged,
+    /// `T | E`, for a `Result` serialized untagged.
+    Union,
+    /// `T`, for when the error is handled out of band and never serialized.
+    Unwrap,
+}
+
+/// A naming convention for `StructToStringBuilder::rename_all`, named like serde's.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Case {
+    /// `snake_case`
+    Snake,
+    /// `camelCase`
+    Camel,
+    /// `PascalCase`
+    Pascal,
+    /// `SCREAMING_SNAKE_CASE`
+    ScreamingSnake,
+}
+
+impl Case {
+    /// Converts a name in any of the cases, or `kebab-case`, such as a field name serde
+    /// already renamed, to this case.
+    pub fn apply(self, name: &str) -> String {
+        let words = split_words(name);
+        match self {
+            Case::Snake => words.join("_"),
+            Case::ScreamingSnake => words
+                .iter()
+                .map(|word| word.to_uppercase())
+                .collect::<Vec<_>>()
+                .join("_"),
+            Case::Camel | Case::Pascal => words
+                .iter()
+                .enumerate()
+                .map(|(i, word)| {
+                    if i == 0 && self == Case::Camel {
+                        return word.clone();
+                    }
+                    let mut chars = word.chars();
+                    match chars.next() {
+                        Some(first) => first.to_uppercase().chain(chars).collect(),
+                        None => String::new(),
+                    }
+                })
+                .collect(),
         }
     }
+}
 
-    let gen = quote! {
-        impl #name {
-            pub fn to_rust_string() -> String {
-                let mut res = String::from("struct ");
-                res.push_str(stringify!(#name));
-                res.push_str(" {\n");
-                res.push_str(#rust_fields.trim_end_matches(",\n"));
-                res.push_str("\n}");
-                res
+/// Splits a name into its lowercased words, at underscores, hyphens and case changes, e.g.
+/// `userID_v2` into `user`, `id` and `v2`. A run of capitals is one word, apart from the last
+/// capital when it starts the next, as in `HTTPServer`.
+fn split_words(name: &str) -> Vec<String> {
+    let chars: Vec<char> = name.chars().collect();
+    let mut words = Vec::new();
+    let mut word = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c == '_' || c == '-' {
+            if !word.is_empty() {
+                words.push(std::mem::take(&mut word));
             }
-
-            pub fn to_go_string() -> String {
-                let mut res = String::from("type ");
-                res.push_str(stringify!(#name));
-                res.push_str(" struct {\n");
-                res.push_str(#go_fields);
-                res.push_str("}");
-                res
+            continue;
+        }
+        if c.is_uppercase() && !word.is_empty() {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if !previous.is_uppercase() || next_is_lowercase {
+                words.push(std::mem::take(&mut word));
             }
+        }
+        word.extend(c.to_lowercase());
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+    words
+}
 
-            pub fn to_python_string() -> String {
-                let mut res = String::from("@dataclass_json\n@dataclass\nclass ");
-                res.push_str(stringify!(#name));
-                res.push_str(":\n");
-                res.push_str(#python_fields);
-                res
-            }
+/// Composes the output options of a derived type in one place. Created with the generated
+/// `string_builder()` function.
+///
+#[cfg_attr(feature = "go", doc = "```")]
+#[cfg_attr(not(feature = "go"), doc = "```ignore")]
+/// use struct_to_string::{Case, Language, StructToString};
+///
+/// #[derive(StructToString)]
+/// struct User {
+///     user_name: String,
+/// }
+///
+/// let go = User::string_builder()
+///     .language(Language::Go)
+///     .indent("\t")
+///     .rename_all(Case::Camel)
+///     .build();
+/// assert_eq!(go, "type User struct {\n\tUserName string `json:\"userName\"`\n}");
+/// ```
+pub struct StructToStringBuilder<T: StructToString + ?Sized> {
+    language: Option<Language>,
+    options: __private::Options,
+    export: bool,
+    schema_hash: bool,
+    marker: std::marker::PhantomData<T>,
+}
 
-            pub fn to_typescript_string() -> String {
-                let mut res = String::from("interface ");
-                res.push_str(stringify!(#name));
-                res.push_str(" {\n");
-                res.push_str(#ts_fields);
-                res.push_str("}");
-                res
-            }
+impl<T: StructToString + ?Sized> StructToStringBuilder<T> {
+    /// Starts with the first enabled language, the default indentation and the field names
+    /// as they are.
+    pub fn new() -> Self {
+        StructToStringBuilder {
+            language: Language::ALL.first().copied(),
+            options: __private::Options::default(),
+            export: false,
+            schema_hash: false,
+            marker: std::marker::PhantomData,
+        }
+    }
 
-            pub fn to_java_string() -> String {
-                let mut res = String::from("public class ");
-                res.push_str(stringify!(#name));
-                res.push_str(" {\n");
-                res.push_str(#java_fields);
-                res.push_str("}");
-                res
-            }
+    /// The language to write the type in.
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
 
-            pub fn to_csharp_string() -> String {
-                let mut res = String::from("public class ");
-                res.push_str(stringify!(#name));
-                res.push_str(" {\n");
-                res.push_str(#csharp_fields);
-                res.push_str("}");
-                res
-            }
-        }
-    };
+    /// The string each level of indentation is written as, e.g. `"\t"`.
+    pub fn indent(mut self, indent: &str) -> Self {
+        self.options.indent = Some(indent.to_string());
+        self
+    }
 
-    gen.into()
-}
+    /// Renames the serialized names of the fields, those of `StructToString::field_names`, to
+    /// the given case. Type names and the Rust output are left as they are.
+    pub fn rename_all(mut self, case: Case) -> Self {
+        self.options.rename_all = Some(case);
+        self
+    }
 
-fn rust_type_to_ts_type(ty: &Type) -> String {
-    match ty {
-        Type::Path(type_path) => {
-            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
-            match last_segment.as_str() {
-                "i32" | "u32" | "i64" | "u64" => "number",
-                "f32" | "f64" => "number",
-                "bool" => "boolean",
-                "String" => "string",
-                "char" => "string",
-                "Option" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("{} | null", rust_type_to_ts_type(inner_type));
-                        }
-                    }
-                    "any"
-                }
-                "Vec" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("{}[]", rust_type_to_ts_type(inner_type));
-                        }
-                    }
-                    "any[]"
-                }
-                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
-            }
-            .to_string()
+    /// Notes the exact Rust type of integer fields in TypeScript, e.g. `// u64`, since they're
+    /// all `number`s there.
+    pub fn integer_widths(mut self, integer_widths: bool) -> Self {
+        self.options.integer_widths = integer_widths;
+        self
+    }
+
+    /// Notes the Rust name of every TypeScript field written under another name, e.g.
+    /// `userId: number; // rust: user_id`.
+    pub fn rust_names(mut self, rust_names: bool) -> Self {
+        self.options.rust_names = rust_names;
+        self
+    }
+
+    /// Declares every property of the TypeScript interface `readonly`.
+    pub fn readonly(mut self, readonly: bool) -> Self {
+        self.options.readonly = readonly;
+        self
+    }
+
+    /// How `Result<T, E>` fields are typed in TypeScript.
+    pub fn result(mut self, result: ResultStrategy) -> Self {
+        self.options.result = result;
+        self
+    }
+
+    /// The collection type `Vec<T>` fields are declared as in Java.
+    pub fn java_collection(mut self, collection: JavaCollection) -> Self {
+        self.options.java_collection = collection;
+        self
+    }
+
+    /// Declares `Option<T>` fields as `java.util.Optional<T>` in Java, instead of a nullable `T`.
+    pub fn java_optional(mut self, java_optional: bool) -> Self {
+        self.options.java_optional = java_optional;
+        self
+    }
+
+    /// Declares the properties with `var` in Kotlin and Swift, instead of `val` and `let`.
+    pub fn mutable(mut self, mutable: bool) -> Self {
+        self.options.mutable = mutable;
+        self
+    }
+
+    /// Prefixes the declaration with `export`, in the languages that have it.
+    pub fn export(mut self, export: bool) -> Self {
+        self.export = export;
+        self
+    }
+
+    /// Precedes the declaration with a comment holding `StructToString::schema_hash`, e.g.
+    /// `// schema hash: 9f3c0e5a7b21d4c8`, so that consumers can tell when it changed.
+    pub fn schema_hash(mut self, schema_hash: bool) -> Self {
+        self.schema_hash = schema_hash;
+        self
+    }
+
+    /// Writes the type out with the chosen options. Empty when no language is enabled.
+    pub fn build(&self) -> String {
+        let Some(language) = self.language else {
+            return String::new();
+        };
+        let mut output = T::to_string_with(language, &self.options);
+
+        #[allow(unreachable_patterns)]
+        let exportable = match language {
+            #[cfg(feature = "typescript")]
+            Language::TypeScript => true,
+            #[cfg(feature = "flow")]
+            Language::Flow => true,
+            _ => false,
+        };
+        if self.export && exportable {
+            output.insert_str(0, "export ");
         }
-        Type::Array(array) => {
-            let inner_type = rust_type_to_ts_type(&array.elem);
-            format!("{}[]", inner_type)
+
+        if self.schema_hash {
+            let comment = format!("schema hash: {:016x}", T::schema_hash());
+            #[allow(unreachable_patterns)]
+            let comment = match language {
+                #[cfg(feature = "python")]
+                Language::Python => format!("# {}", comment),
+                #[cfg(feature = "elixir")]
+                Language::Elixir => format!("# {}", comment),
+                #[cfg(feature = "ocaml")]
+                Language::OCaml => format!("(* {} *)", comment),
+                _ => format!("// {}", comment),
+            };
+            output.insert_str(0, &format!("{}\n", comment));
         }
-        Type::Tuple(tuple) => {
-            let types: Vec<String> = tuple
-                .elems
-                .iter()
-                .map(|elem| rust_type_to_ts_type(elem))
-                .collect();
-            format!("[{}]", types.join(", "))
+        output
+    }
+}
+
+impl<T: StructToString + ?Sized> Default for StructToStringBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Implemented by `#[derive(StructToString)]`, so that generic code can be bounded on types
+/// that can be written out in other languages.
+///
+/// The derive also generates these as inherent associated functions, which can be called
+/// without importing the trait, and only has the methods of the enabled languages.
+///
+#[cfg_attr(feature = "typescript", doc = "```")]
+#[cfg_attr(not(feature = "typescript"), doc = "```ignore")]
+/// use struct_to_string::StructToString;
+///
+/// fn document<T: StructToString>() -> String {
+///     T::to_typescript_string()
+/// }
+///
+/// #[derive(StructToString)]
+/// struct User {
+///     name: String,
+/// }
+///
+/// assert_eq!(document::<User>(), "interface User {\n    name: string;\n}");
+/// ```
+pub trait StructToString {
+    #[cfg(feature = "rust")]
+    fn to_rust_string() -> String;
+    #[cfg(feature = "typescript")]
+    fn to_typescript_string() -> String;
+    #[cfg(feature = "python")]
+    fn to_python_string() -> String;
+    #[cfg(feature = "go")]
+    fn to_go_string() -> String;
+    #[cfg(feature = "java")]
+    fn to_java_string() -> String;
+    #[cfg(feature = "csharp")]
+    fn to_csharp_string() -> String;
+    #[cfg(feature = "kotlin")]
+    fn to_kotlin_string() -> String;
+    #[cfg(feature = "swift")]
+    fn to_swift_string() -> String;
+    #[cfg(feature = "cpp")]
+    fn to_cpp_string() -> String;
+    #[cfg(feature = "flow")]
+    fn to_flow_string() -> String;
+    #[cfg(feature = "elixir")]
+    fn to_elixir_string() -> String;
+    #[cfg(feature = "ocaml")]
+    fn to_ocaml_string() -> String;
+    #[cfg(feature = "fsharp")]
+    fn to_fsharp_string() -> String;
+
+    /// The names of the fields in the non-Rust outputs, i.e. after serde's `skip` and
+    /// `rename`. Empty for enums and newtypes.
+    fn field_names() -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// A stable hash of the serialized names and the types of the fields, and the serialized
+    /// names of the variants, which changes whenever the serialized shape of the type does.
+    fn schema_hash() -> u64;
+
+    /// Like `to_string_in`, with the options of a `StructToStringBuilder`.
+    #[doc(hidden)]
+    fn to_string_with(language: Language, options: &__private::Options) -> String;
+
+    /// Returns the definition of this type in the given language.
+    fn to_string_in(language: Language) -> String {
+        match language {
+            #[cfg(feature = "rust")]
+            Language::Rust => Self::to_rust_string(),
+            #[cfg(feature = "typescript")]
+            Language::TypeScript => Self::to_typescript_string(),
+            #[cfg(feature = "python")]
+            Language::Python => Self::to_python_string(),
+            #[cfg(feature = "go")]
+            Language::Go => Self::to_go_string(),
+            #[cfg(feature = "java")]
+            Language::Java => Self::to_java_string(),
+            #[cfg(feature = "csharp")]
+            Language::CSharp => Self::to_csharp_string(),
+            #[cfg(feature = "kotlin")]
+            Language::Kotlin => Self::to_kotlin_string(),
+            #[cfg(feature = "swift")]
+            Language::Swift => Self::to_swift_string(),
+            #[cfg(feature = "cpp")]
+            Language::Cpp => Self::to_cpp_string(),
+            #[cfg(feature = "flow")]
+            Language::Flow => Self::to_flow_string(),
+            #[cfg(feature = "elixir")]
+            Language::Elixir => Self::to_elixir_string(),
+            #[cfg(feature = "ocaml")]
+            Language::OCaml => Self::to_ocaml_string(),
+            #[cfg(feature = "fsharp")]
+            Language::FSharp => Self::to_fsharp_string(),
         }
-        _ => "any".to_string(), // Fallback to 'any' for unsupported or complex types.
     }
 }
 
-fn rust_type_to_python_type(ty: &Type) -> String {
-    match ty {
-        Type::Path(type_path) => {
-            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
-            match last_segment.as_str() {
-                "i32" | "u32" | "i64" | "u64" => "int",
-                "f32" | "f64" => "float",
-                "bool" => "bool",
-                "String" => "str",
-                "char" => "str",
-                "Option" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("Optional[{}]", rust_type_to_python_type(inner_type));
-                        }
-                    }
-                    "any"
-                }
-                "Vec" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("List[{}]", rust_type_to_python_type(inner_type));
-                        }
-                    }
-                    "any[]"
-                }
-                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+/// Support code for the generated implementations. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use std::borrow::Cow;
+    use std::marker::PhantomData;
+
+    use crate::{Case, JavaCollection, Language, ResultStrategy};
+
+    /// The options of a `StructToStringBuilder`, which the generated code reads.
+    #[derive(Debug, Clone, Default)]
+    pub struct Options {
+        pub indent: Option<String>,
+        pub rename_all: Option<Case>,
+        pub integer_widths: bool,
+        pub rust_names: bool,
+        pub readonly: bool,
+        pub result: ResultStrategy,
+        pub java_collection: JavaCollection,
+        pub java_optional: bool,
+        pub mutable: bool,
+    }
+
+    impl Options {
+        /// The serialized name of a field, in the case picked by `rename_all`.
+        pub fn field_name<'a>(&self, name: &'a str) -> Cow<'a, str> {
+            match self.rename_all {
+                Some(case) => Cow::Owned(case.apply(name)),
+                None => Cow::Borrowed(name),
             }
-            .to_string()
         }
-        Type::Array(array) => {
-            let inner_type = rust_type_to_python_type(&array.elem);
-            format!("List[{}]", inner_type)
-        }
-        Type::Tuple(tuple) => {
-            let types: Vec<String> = tuple
-                .elems
-                .iter()
-                .map(|elem| rust_type_to_python_type(elem))
-                .collect();
-            format!("Tuple[{}]", types.join(", "))
-        }
-        _ => "any".to_string(), // Fallback to 'any' for unsupported or complex types.
     }
-}
 
-fn rust_type_to_go_type(ty: &Type) -> String {
-    match ty {
-        Type::Path(type_path) => {
-            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
-            match last_segment.as_str() {
-                "i8" => "int8",
-                "u8" => "uint8",
-                "i16" => "int16",
-                "u16" => "uint16",
-                "i32" => "int32",
-                "u32" => "uint32",
-                "i64" => "int64",
-                "u64" => "uint64",
-                "i128" => "big.Int",
-                "u128" => "big.Int",
-                "f32" => "float32",
-                "f64" => "float64",
-                "bool" => "bool",
-                "String" => "string",
-                "char" => "rune",
-                "&str" => "string",
-                "Option" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("*{}", rust_type_to_go_type(inner_type));
-                        }
-                    }
-                    "any"
-                }
-                "Vec" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("[]{}", rust_type_to_go_type(inner_type));
-                        }
-                    }
-                    "any[]"
-                }
-                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+    /// A part of the field lines of one output.
+    pub enum Piece<'a> {
+        Text(&'a str),
+        /// A field's serialized name.
+        Name(&'a str),
+        /// The field lines of a flattened type, or the given pieces when it doesn't derive
+        /// `StructToString`.
+        Flattened(Option<String>, &'a [Piece<'a>]),
+    }
+
+    /// Writes out the field lines of one output.
+    pub fn render(options: &Options, pieces: &[Piece]) -> String {
+        let mut res = String::new();
+        for piece in pieces {
+            match piece {
+                Piece::Text(text) => res.push_str(text),
+                Piece::Name(name) => res.push_str(&options.field_name(name)),
+                Piece::Flattened(Some(fields), _) => res.push_str(fields),
+                Piece::Flattened(None, fallback) => res.push_str(&render(options, fallback)),
             }
-            .to_string()
         }
-        Type::Array(array) => {
-            let inner_type = rust_type_to_go_type(&array.elem);
-            let array_length = match &array.len {
-                syn::Expr::Lit(expr_lit) => {
-                    if let syn::Lit::Int(lit_int) = &expr_lit.lit {
-                        lit_int.base10_parse::<usize>().unwrap()
-                    } else {
-                        let lit_token_stream = expr_lit.lit.to_token_stream();
-                        panic!(
-                            "Invalid array length expression: {}",
-                            quote!(#lit_token_stream)
-                        );
-                    }
-                }
-                _ => panic!("Invalid array length expression:"),
-            };
-            format!("[{}]{}", array_length, inner_type)
+        res
+    }
+
+    /// The trailing comment of a TypeScript field, noting its Rust name and its `integer` type
+    /// when the options ask for them, followed by `note`.
+    pub fn typescript_comment(
+        options: &Options,
+        name: &str,
+        rust_name: &str,
+        integer: Option<&str>,
+        note: Option<&str>,
+    ) -> String {
+        let mut notes = Vec::new();
+        if options.rust_names && options.field_name(name) != rust_name {
+            notes.push(format!("rust: {}", rust_name));
         }
-        Type::Tuple(tuple) => {
-            let types: Vec<String> = tuple
-                .elems
-                .iter()
-                .map(|elem| rust_type_to_go_type(elem))
-                .collect();
-            format!(
-                "struct{{}} // CANNOT CONVERT THIS TO THE GO PROGRAMMING LANGUAGE. TUPLES ARE UNSUPPORTED BY GO: ({})",
-                types.join(", ")
-            )
+        if options.integer_widths {
+            notes.extend(integer.map(str::to_string));
+        }
+        notes.extend(note.map(str::to_string));
+        if notes.is_empty() {
+            String::new()
+        } else {
+            format!(" // {}", notes.join(", "))
         }
-        _ => "any".to_string(), // Fallback to 'any' for unsupported or complex types.
     }
-}
 
-fn rust_type_to_java_type(ty: &Type) -> String {
-    match ty {
-        Type::Path(type_path) => {
-            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
-            match last_segment.as_str() {
-                "i8" => "byte",
-                "u8" => "short",
-                "i16" => "short",
-                "u16" => "int",
-                "i32" => "int",
-                "u32" => "long",
-                "i64" => "long",
-                "u64" => "BigInteger",
-                "i128" => "BigInteger",
-                "u128" => "BigInteger",
-                "f32" => "float",
-                "f64" => "double",
-                "bool" => "boolean",
-                "String" => "String",
-                "char" => "char",
-                "Option" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!(
-                                "{}",
-                                convert_java_primitive_type_to_wrapper_class(
-                                    rust_type_to_java_type(inner_type).as_str()
-                                )
-                            );
-                        }
-                    }
-                    "Object"
-                }
-                "Vec" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!(
-                                "List<{}>",
-                                convert_java_primitive_type_to_wrapper_class(
-                                    rust_type_to_java_type(inner_type).as_str()
-                                )
-                            );
-                        }
-                    }
-                    "List<Object>"
-                }
-                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+    /// Replaces each leading `unit` of every line of `output` with the `indent` option, if set.
+    pub fn reindent(output: String, unit: &str, options: &Options) -> String {
+        let Some(indent) = &options.indent else {
+            return output;
+        };
+        let mut res = String::new();
+        for line in output.split_inclusive('\n') {
+            let mut rest = line;
+            while let Some(stripped) = rest.strip_prefix(unit) {
+                res.push_str(indent);
+                rest = stripped;
             }
-            .to_string()
+            res.push_str(rest);
         }
-        Type::Array(array) => {
-            let inner_type = rust_type_to_java_type(&array.elem);
-            format!("{}[]", &inner_type)
+        res
+    }
+
+    /// Implemented by `#[derive(StructToString)]` so that the `*_recursive` methods can
+    /// descend into field types that also derive `StructToString`.
+    pub trait CollectDefinitions {
+        /// Pushes the definitions of the types this type references, followed by its own,
+        /// onto `out`. Types whose `std::any::type_name` is already listed in `seen` are
+        /// skipped, which is also what stops cyclic references from recursing forever.
+        fn collect_definitions(
+            language: Language,
+            seen: &mut Vec<&'static str>,
+            out: &mut Vec<String>,
+        );
+
+        /// The field lines this type contributes to a type flattening it with
+        /// `#[serde(flatten)]`, for the output identified by `kind`. Only structs have any.
+        fn flattened_fields(_kind: &str, _options: &Options) -> Option<String> {
+            None
         }
-        Type::Tuple(tuple) => {
-            let types: Vec<String> = tuple
-                .elems
-                .iter()
-                .map(|elem| {
-                    convert_java_primitive_type_to_wrapper_class(
-                        rust_type_to_java_type(elem).as_str(),
-                    )
-                })
-                .collect();
-            format!("Tuple<{}>", types.join(", "))
+
+        /// The placeholder value of this type in a TOML example, e.g. an enum's first variant.
+        /// `None` for types written as a table.
+        fn toml_value() -> Option<String> {
+            None
+        }
+
+        /// The `key = value` lines of this type as a TOML table at `prefix`, followed by the
+        /// tables nested in it. Only structs have any. `seen` stops a struct nested in itself
+        /// from recursing forever.
+        fn toml_table(_prefix: &str, _seen: &mut Vec<&'static str>) -> Option<(String, String)> {
+            None
         }
-        _ => "Object".to_string(), // Fallback to 'Object' for unsupported or complex types.
     }
-}
 
-fn convert_java_primitive_type_to_wrapper_class(inner_type: &str) -> String {
-    match inner_type {
-        "byte" => "Byte",
-        "short" => "Short",
-        "int" => "Integer",
-        "long" => "Long",
-        "float" => "Float",
-        "double" => "Double",
-        "char" => "Character",
-        "boolean" => "Boolean",
-        _ => inner_type,
+    /// Picked for field types implementing `CollectDefinitions`.
+    pub trait ViaDerive {
+        fn collect_definitions(
+            &self,
+            language: Language,
+            seen: &mut Vec<&'static str>,
+            out: &mut Vec<String>,
+        );
+
+        fn flattened_fields(&self, kind: &str, options: &Options) -> Option<String>;
+
+        fn toml_value(&self) -> Option<String>;
+
+        fn toml_table(
+            &self,
+            prefix: &str,
+            seen: &mut Vec<&'static str>,
+        ) -> Option<(String, String)>;
     }
-    .to_string()
-}
 
-fn rust_type_to_csharp_type(ty: &Type) -> String {
-    match ty {
-        Type::Path(type_path) => {
-            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
-            match last_segment.as_str() {
-                "i8" => "sbyte",
-                "u8" => "byte",
-                "i16" => "short",
-                "u16" => "ushort",
-                "i32" => "int",
-                "u32" => "uint",
-                "i64" => "long",
-                "u64" => "ulong",
-                "i128" => "BigInteger",
-                "u128" => "BigInteger",
-                "f32" => "float",
-                "f64" => "double",
-                "bool" => "bool",
-                "String" => "string",
-                "char" => "char",
-                "Option" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("{}?", rust_type_to_csharp_type(inner_type));
-                        }
-                    }
-                    "Object"
-                }
-                "Vec" => {
-                    let arguments = &type_path.path.segments.last().unwrap().arguments;
-                    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
-                        if let Some(syn::GenericArgument::Type(inner_type)) =
-                            angle_bracketed_args.args.first()
-                        {
-                            return format!("List<{}>", rust_type_to_csharp_type(inner_type));
-                        }
-                    }
-                    "List<Object>"
-                }
-                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
-            }
-            .to_string()
+    impl<T: CollectDefinitions + ?Sized> ViaDerive for PhantomData<T> {
+        fn collect_definitions(
+            &self,
+            language: Language,
+            seen: &mut Vec<&'static str>,
+            out: &mut Vec<String>,
+        ) {
+            T::collect_definitions(language, seen, out);
         }
-        Type::Array(array) => {
-            let inner_type = rust_type_to_csharp_type(&array.elem);
-            format!("{}[]", &inner_type)
+
+        fn flattened_fields(&self, kind: &str, options: &Options) -> Option<String> {
+            T::flattened_fields(kind, options)
         }
-        Type::Tuple(tuple) => {
-            let types: Vec<String> = tuple
-                .elems
-                .iter()
-                .map(|elem| rust_type_to_csharp_type(elem))
-                .collect();
-            format!("({})", types.join(", "))
+
+        fn toml_value(&self) -> Option<String> {
+            T::toml_value()
+        }
+
+        fn toml_table(
+            &self,
+            prefix: &str,
+            seen: &mut Vec<&'static str>,
+        ) -> Option<(String, String)> {
+            T::toml_table(prefix, seen)
+        }
+    }
+
+    /// Picked, through autoref, for field types that don't derive `StructToString`. Those
+    /// are only referenced by name.
+    pub trait ViaFallback {
+        fn collect_definitions(
+            &self,
+            _language: Language,
+            _seen: &mut Vec<&'static str>,
+            _out: &mut Vec<String>,
+        ) {
         }
-        _ => "Object".to_string(), // Fallback to 'Object' for unsupported or complex types.
+
+        fn flattened_fields(&self, _kind: &str, _options: &Options) -> Option<String> {
+            None
+        }
+
+        fn toml_value(&self) -> Option<String> {
+            None
+        }
+
+        fn toml_table(
+            &self,
+            _prefix: &str,
+            _seen: &mut Vec<&'static str>,
+        ) -> Option<(String, String)> {
+            None
+        }
+    }
+
+    impl<T: ?Sized> ViaFallback for &PhantomData<T> {}
+
+    /// Joins the definitions of `T` and every type it references, dependencies first.
+    pub fn recursive_definitions<T: CollectDefinitions + ?Sized>(language: Language) -> String {
+        let mut seen = Vec::new();
+        let mut out = Vec::new();
+        T::collect_definitions(language, &mut seen, &mut out);
+        out.iter()
+            .map(|definition| definition.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n\n")
     }
 }
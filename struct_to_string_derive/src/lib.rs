@@ -0,0 +1,6167 @@
+//! The procedural macros behind the `struct_to_string` crate.
+//!
+//! This crate is an implementation detail; depend on `struct_to_string` instead,
+//! which re-exports everything defined here.
+extern crate proc_macro;
+use proc_macro::TokenStream;
+use quote::quote;
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::{parse_macro_input, DeriveInput, Ident, Token, Type};
+
+/// The `StructToString` macro derives a `to_string` function for the struct.
+///
+/// # Example
+/// ```
+/// use struct_to_string::StructToString;
+///
+/// #[derive(StructToString)]
+/// struct MyStruct {
+///     field1: i32,
+///     field2: String,
+/// }
+///
+/// let my_struct_as_rust_string = MyStruct::to_rust_string();
+///
+/// // Struct to string can also be used to convert structs to other programming languages,
+/// // including Python, TypeScript, Go, Java, C#, Kotlin, Swift, C++, Flow, Elixir, OCaml and F#.
+///
+/// let my_struct_as_c_sharp_string = MyStruct::to_csharp_string();
+/// ```
+///
+/// # Recursive output
+///
+/// Every `to_*_string` method has a `to_*_string_recursive` sibling which also emits the
+/// definitions of the field types that derive `StructToString`, dependencies first. Each
+/// type is emitted once, so cyclic references between types simply end where a type has
+/// already been written. Types are told apart by `std::any::type_name`, i.e. by their full
+/// path and type arguments: `a::User` and `b::User` are both emitted, and so are the
+/// dependencies of both `Page<A>` and `Page<B>`, with the definition of `Page` written once.
+/// Field types that don't derive `StructToString` are only referenced by name.
+///
+/// # Field attributes
+///
+/// Fields can be annotated with `#[struct_to_string(...)]` to tweak the generated output:
+///
+/// - `go_validate = "..."` emits a go-playground/validator `validate:"..."` struct tag in the Go output.
+/// - `map_keys("A", "B", ...)` types a map field as `Record<"A" | "B", V>` in TypeScript, for maps
+///   keyed by an enum.
+/// - `rust`, `typescript` (or `ts`), `python`, `go`, `java`, `csharp`, `kotlin`, `swift`, `cpp`,
+///   `flow`, `elixir`, `ocaml` or `fsharp` `= "..."` replaces the field's type in that language's output verbatim, e.g.
+///   `#[struct_to_string(typescript = "Date")]`. Any other key is a compile error.
+/// - `as = "..."` replaces the field's type in every output except the Rust one. A
+///   per-language override still takes precedence.
+/// - `pattern = "..."` declares a field, typically a newtype like `CountryCode(String)`, as a
+///   string matching the regular expression. The pattern is emitted in the OpenAPI and JSON
+///   schemas and the field is a plain string in the other outputs.
+/// - `format = "..."` declares a field, typically a newtype like `Email(String)`, as a string of
+///   the given JSON Schema format, e.g. `email`, emitted like `pattern`.
+///
+/// The serde field attributes `#[serde(skip)]`, `#[serde(skip_serializing)]`,
+/// `#[serde(rename = "...")]` and `#[serde(with = "serde_bytes")]`, and the container attribute
+/// `#[serde(rename_all = "...")]`, are also honored by every output except the Rust one, which
+/// always reproduces the original definition.
+/// `#[serde(flatten)]` inlines the fields of the flattened type into the language outputs when
+/// that type derives `StructToString` too; otherwise the field is kept as it is. TypeScript
+/// interfaces extend the flattened type instead.
+///
+/// # Type attributes
+///
+/// The type itself accepts `#[struct_to_string(...)]` as well:
+///
+/// - `<language>_prefix = "..."` and `<language>_suffix = "..."` decorate the type's name in that
+///   language's output, e.g. `#[struct_to_string(csharp_prefix = "I", java_suffix = "DTO")]`.
+///   The languages are those of the per-field type overrides, except `rust`. Fields of other
+///   types referring to a decorated type still use its plain name.
+/// - `pub_only` leaves the fields that aren't `pub` out of every output but the Rust one, as
+///   they aren't part of the type's public API.
+/// - `indent = "..."` writes the fields with that string for each level of indentation, e.g.
+///   `#[struct_to_string(indent = "\t")]`, instead of four spaces, or two in Zod and Flow.
+///   Flattened fields keep the indentation of the type they come from.
+/// - `typescript_option = "..."` wraps the TypeScript type of `Option<T>` fields in the given
+///   generic type instead of `T | null`, e.g. `Option<number>` for fp-ts or `Maybe<number>`.
+/// - `display = "..."` is the template `to_rust_display_impl_string()` writes the struct with,
+///   e.g. `"{id}-{name}"`. Placeholders must name fields, and may carry a format spec.
+///
+/// # Enums
+///
+/// Enums whose variants carry no data are supported too. They become a union of string
+/// literals in TypeScript, a `str` `Enum` in Python, a string type with constants in Go, an
+/// `enum` in Java and C#, an `enum class` in Kotlin and C++ and a `String` backed `enum` in Swift.
+/// Python, Java and Kotlin use `SCREAMING_SNAKE_CASE` variant names, Elixir a union of atoms
+/// in a module's `@type t` and OCaml and F# a union of the variant names. Serde's `skip` and
+/// `rename` are honored on variants as they are on fields. An enum with no variants, or whose
+/// every variant is skipped, becomes the bottom type, e.g. `never` in TypeScript, where there's
+/// one and an empty enum elsewhere.
+/// Enums also get `to_proto_string()`, emitting a proto3 `enum` numbered like the Rust
+/// discriminants, unless a discriminant isn't an integer literal, such as `1 << 2`, or doesn't
+/// fit in proto3's `int32`.
+///
+/// Variants carrying data are supported in `#[serde(untagged)]` enums, which serialize as the
+/// variant's data alone. They become a plain union of the variants' shapes in TypeScript, Flow,
+/// Python and Elixir, e.g. `type Shape = null | number | { w: number; h: number };`, and the top type
+/// (`any`, `Object`, ...) in the other languages.
+///
+/// # Newtypes
+///
+/// Tuple structs with a single field, such as `struct UserId(u64)`, serialize as that field,
+/// so they become an alias of its type: a `NewType` in Python, a `type` alias in TypeScript,
+/// Flow, Go, OCaml and F#, a `typealias` in Kotlin and Swift, a `using` alias in C++ and a module's
+/// `@type t` in Elixir. Java and C#
+/// have no aliases and get a class wrapping a `value` field instead.
+#[proc_macro_derive(StructToString, attributes(struct_to_string, serde))]
+pub fn struct_to_string(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = parse_macro_input!(input);
+    if let syn::Data::Enum(data_enum) = &ast.data {
+        return match enum_to_string(&ast, data_enum) {
+            Ok(gen) => gen.into(),
+            Err(err) => err.to_compile_error().into(),
+        };
+    }
+    if let syn::Data::Union(data_union) = &ast.data {
+        return syn::Error::new_spanned(
+            data_union.union_token,
+            "StructToString only supports structs and enums",
+        )
+        .to_compile_error()
+        .into();
+    }
+    if let syn::Data::Struct(syn::DataStruct {
+        fields: syn::Fields::Unnamed(fields),
+        ..
+    }) = &ast.data
+    {
+        if fields.unnamed.len() == 1 {
+            return match newtype_to_string(&ast, &fields.unnamed[0]) {
+                Ok(gen) => gen.into(),
+                Err(err) => err.to_compile_error().into(),
+            };
+        }
+    }
+    let name = ast.ident;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    // Only type parameters make it into the output strings; lifetimes and const
+    // parameters have no equivalent in most target languages.
+    let type_params: Vec<String> = ast
+        .generics
+        .type_params()
+        .map(|type_param| type_param.ident.to_string())
+        .collect();
+    let container_attributes = match ContainerAttributes::from_attrs(&ast.attrs) {
+        Ok(container_attributes) => container_attributes,
+        Err(err) => return err.to_compile_error().into(),
+    };
+    let angle_bracketed_name = generic_type_name(&name.to_string(), &type_params, "<", ">");
+    let rust_struct_keyword = format!("{}struct ", visibility_to_string(&ast.vis));
+    let rust_attrs = item_attributes_to_string(&ast.attrs);
+    // The declaration reproducing the original keeps every generic parameter, unlike the
+    // other Rust outputs, so that it compiles.
+    let mut rust_full_name = format!("{}{}", name, rust_generics_string(&ast.generics));
+    if let Some(where_clause) = &ast.generics.where_clause {
+        rust_full_name.push(' ');
+        rust_full_name.push_str(rust_generics_string(where_clause).trim_end_matches(", "));
+    }
+    let decorated_name = |language: &str, open: &str, close: &str| {
+        let name = container_attributes.decorated_name(language, &name.to_string());
+        generic_type_name(&name, &type_params, open, close)
+    };
+    let ts_name = decorated_name("typescript", "<", ">");
+    let flow_name = decorated_name("flow", "<", ">");
+    // Elixir modules have no type parameters.
+    let elixir_name = container_attributes.decorated_name("elixir", &name.to_string());
+    let elixir_indent = container_attributes.indent.as_deref().unwrap_or("  ");
+    // OCaml type names are lowercase, and can't be generic the way they are here either.
+    let ocaml_name =
+        ocaml_type_name(&container_attributes.decorated_name("ocaml", &name.to_string()));
+    // F# type parameters are written `'T`, which the field types don't know of, so they're
+    // left out.
+    let fsharp_name = container_attributes.decorated_name("fsharp", &name.to_string());
+    // Zod schemas and resolver maps are values, so they have no type parameters.
+    let ts_value_name = container_attributes.decorated_name("typescript", &name.to_string());
+    let python_name = decorated_name("python", "[", "]");
+    let java_name = decorated_name("java", "<", ">");
+    let csharp_name = decorated_name("csharp", "<", ">");
+    let kotlin_name = decorated_name("kotlin", "<", ">");
+    let swift_name = decorated_name("swift", "<", ">");
+    let cpp_name = container_attributes.decorated_name("cpp", &name.to_string());
+    let cpp_template = match type_params.len() {
+        0 => String::new(),
+        _ => format!(
+            "template <{}>\n",
+            type_params
+                .iter()
+                .map(|type_param| format!("typename {}", type_param))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let go_type_params: Vec<String> = type_params
+        .iter()
+        .map(|type_param| format!("{} any", type_param))
+        .collect();
+    let go_name = generic_type_name(
+        &container_attributes.decorated_name("go", &name.to_string()),
+        &go_type_params,
+        "[",
+        "]",
+    );
+
+    let mut rust_fields = String::new();
+    let mut rust_short_fields = String::new();
+    let mut rust_attr_fields = String::new();
+    let mut go_fields = FieldList::new("go");
+    let mut go_imports = std::collections::BTreeSet::new();
+    let mut python_fields = FieldList::new("python");
+    let mut python_imports = std::collections::BTreeSet::new();
+    let mut python_none_default_fields = FieldList::new("python_none_defaults");
+    let mut python_modern_fields = FieldList::new("python_modern");
+    let mut pydantic_fields = FieldList::new("pydantic");
+    let mut ts_fields = FieldList::new("typescript");
+    let mut graphql_resolvers = FieldList::new("graphql_resolvers");
+    let mut zod_fields = FieldList::new("zod");
+    let mut flow_fields = FieldList::new("flow");
+    let mut elixir_fields = FieldList::new("elixir");
+    let mut elixir_keys = FieldList::new("elixir_keys");
+    let mut ocaml_fields = FieldList::new("ocaml");
+    let mut fsharp_fields = FieldList::new("fsharp");
+    let mut java_fields = FieldList::new("java");
+    let mut java_record_components = FieldList::new("java_record");
+    let mut csharp_fields = FieldList::new("csharp");
+    let mut csharp_record_parameters = FieldList::new("csharp_record");
+    let mut csharp_property_fields = FieldList::new("csharp_property");
+    let mut kotlin_fields = FieldList::new("kotlin");
+    let mut swift_fields = FieldList::new("swift");
+    let mut cpp_fields = FieldList::new("cpp");
+    let mut referenced_types = Vec::new();
+    let mut ts_extends = Vec::new();
+    let mut rust_new_params = Vec::new();
+    let mut rust_new_fields = Vec::new();
+    // Every field list that `#[serde(flatten)]` fields are inlined into. The TypeScript
+    // interfaces extend the flattened types instead.
+    macro_rules! field_lists {
+        () => {
+            [
+                &mut go_fields,
+                &mut python_fields,
+                &mut python_none_default_fields,
+                &mut python_modern_fields,
+                &mut pydantic_fields,
+                &mut zod_fields,
+                &mut graphql_resolvers,
+                &mut flow_fields,
+                &mut elixir_fields,
+                &mut elixir_keys,
+                &mut ocaml_fields,
+                &mut fsharp_fields,
+                &mut java_fields,
+                &mut java_record_components,
+                &mut csharp_fields,
+                &mut csharp_record_parameters,
+                &mut csharp_property_fields,
+                &mut kotlin_fields,
+                &mut swift_fields,
+                &mut cpp_fields,
+            ]
+        };
+    }
+    let mut openapi_properties = String::new();
+    let mut openapi_required = String::new();
+    let mut openapi_parameters = String::new();
+    let mut json_schema_properties = Vec::new();
+    let mut json_schema_required = Vec::new();
+    let mut csv_columns = String::new();
+    let mut field_names = Vec::new();
+    let mut hcl_variables = Vec::new();
+    let mut toml_statements = Vec::new();
+    let mut csv_errors = Vec::new();
+    let shape_hash = match schema_hash(&ast.data, container_attributes.rename_all) {
+        Ok(shape_hash) => shape_hash,
+        Err(err) => return err.to_compile_error().into(),
+    };
+
+    if let syn::Data::Struct(data_struct) = ast.data {
+        for field in data_struct.fields {
+            let field_attributes = match FieldAttributes::from_attrs(&field.attrs) {
+                Ok(field_attributes) => field_attributes,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            let field_name = match field.ident {
+                Some(field_name) => field_name,
+                None => {
+                    return syn::Error::new_spanned(
+                        &field,
+                        "tuple structs with more than one field are not yet supported",
+                    )
+                    .to_compile_error()
+                    .into()
+                }
+            };
+            let field_type = field.ty;
+            let field_type_tokens = rust_type_string(&field_type);
+
+            let is_optional = match &field_type {
+                Type::Path(type_path) => {
+                    let last_segment = &type_path.path.segments.last().unwrap().ident;
+                    last_segment == "Option"
+                }
+                _ => false,
+            };
+
+            // Rust representation
+            for cfg in field
+                .attrs
+                .iter()
+                .filter(|attr| attr.path().is_ident("cfg"))
+            {
+                rust_fields.push_str(&format!("    {}\n", attribute_to_string(cfg)));
+                rust_short_fields.push_str(&format!("    {}\n", attribute_to_string(cfg)));
+            }
+            rust_fields.push_str(&format!(
+                "    {}{}: {},\n",
+                visibility_to_string(&field.vis),
+                field_name,
+                field_attributes
+                    .type_override("rust")
+                    .unwrap_or(&field_type_tokens)
+            ));
+            let short_field_type = short_type_names(&field_type);
+            rust_short_fields.push_str(&format!(
+                "    {}{}: {},\n",
+                visibility_to_string(&field.vis),
+                field_name,
+                field_attributes
+                    .type_override("rust")
+                    .map(str::to_string)
+                    .unwrap_or_else(|| rust_type_string(&short_field_type))
+            ));
+            for line in item_attributes_to_string(&field.attrs).lines() {
+                rust_attr_fields.push_str(&format!("    {}\n", line));
+            }
+            rust_attr_fields.push_str(&format!(
+                "    {}{}: {},\n",
+                visibility_to_string(&field.vis),
+                field_name,
+                field_type_tokens
+            ));
+            rust_new_params.push(format!("{}: {}", field_name, field_type_tokens));
+            collect_referenced_types(&field_type, &type_params, &mut referenced_types);
+            rust_new_fields.push(field_name.to_string());
+
+            // The Rust representation mirrors the source definition, while every other
+            // language describes the serialized shape, so serde's skip and rename apply.
+            if field_attributes.skip {
+                continue;
+            }
+            // A `PhantomData` marker isn't part of the data at all.
+            if matches!(&field_type, Type::Path(type_path)
+                if type_path.path.segments.last().unwrap().ident == "PhantomData")
+            {
+                continue;
+            }
+            if container_attributes.pub_only && !matches!(field.vis, syn::Visibility::Public(_)) {
+                continue;
+            }
+
+            let rust_field_name = field_name.to_string();
+            let field_name = match (&field_attributes.rename, container_attributes.rename_all) {
+                (Some(rename), _) => rename.clone(),
+                (None, Some(rule)) => rule.apply_to_field(&rust_field_name),
+                (None, None) => rust_field_name.clone(),
+            };
+
+            // `#[serde(with = "serde_bytes")]` serializes a `Vec<u8>` like a `ByteBuf`.
+            let field_type = if field_attributes.serde_bytes {
+                byte_vec_as_byte_buf(&field_type)
+            } else {
+                field_type
+            };
+
+            // A field constrained by a pattern or a format is a string on the wire, whatever its
+            // Rust type.
+            let field_type =
+                if field_attributes.pattern.is_some() || field_attributes.format.is_some() {
+                    constrained_string(&field_type)
+                } else {
+                    field_type
+                };
+
+            // A flattened field is replaced by the fields of its type when that type derives
+            // `StructToString` too, which is only known once the outputs are generated.
+            let flattened = match &field_type {
+                Type::Path(type_path) if field_attributes.flatten => {
+                    let name = type_path.path.segments.last().unwrap().ident.to_string();
+                    (!type_params.contains(&name)).then_some(&field_type)
+                }
+                _ => None,
+            };
+            for field_list in field_lists!() {
+                field_list.start_field(flattened);
+            }
+            if flattened.is_none() {
+                field_names.push(field_name.clone());
+            }
+
+            // Notes about the Rust type are carried along as trailing comments.
+            let note = rust_type_note(&field_type);
+            // Nonzero integers serialize as the plain integer, which the note above calls out.
+            let field_type = nonzero_as_integer(&field_type);
+            // Borrowed and shared strings, and `Cow`s, serialize as what they hold.
+            let field_type = string_like_as_string(&field_type);
+            // TypeScript and Python have no single-character type, so `char` is called out there.
+            let string_note = note.clone().or_else(|| {
+                rust_type_contains(&field_type, "char").then(|| "single character".to_string())
+            });
+            // The languages without unions type a `Result` as any object, so its shape is noted.
+            let note = note.or_else(|| {
+                rust_type_contains(&field_type, "Result")
+                    .then(|| "Result, either {\"Ok\": ...} or {\"Err\": ...}".to_string())
+            });
+            let comment = |marker: &str, note: &Option<String>| match note {
+                Some(note) => format!(" {} {}", marker, note),
+                None => String::new(),
+            };
+
+            let ts_optional_mark = if is_optional { "?" } else { "" };
+
+            // TypeScript representation
+            let ts_options = TsTypeOptions {
+                option_wrapper: container_attributes.typescript_option.as_deref(),
+                ..TsTypeOptions::default()
+            };
+            // The type of a `Result` field depends on the builder's `result` option.
+            let ts_types = ["", "Union", "Unwrap"].map(|result| {
+                let ts_options = TsTypeOptions {
+                    result,
+                    ..ts_options
+                };
+                match &field_attributes.map_keys {
+                    Some(keys) if field_attributes.type_override("typescript").is_none() => {
+                        rust_type_to_ts_keyed_record(&field_type, keys)
+                    }
+                    _ => field_attributes.mapped_type("typescript", &field_type, |ty| {
+                        rust_type_to_ts_type_with(ty, ts_options)
+                    }),
+                }
+            });
+            // The interfaces extend a flattened type rather than repeating its fields.
+            if let Some(flattened) = flattened {
+                ts_extends.push(rust_type_to_ts_type(flattened));
+            } else {
+                ts_fields.push_str("    ");
+                ts_fields.push_tokens(quote!(if options.readonly { "readonly " } else { "" }));
+                ts_fields.push_field("", &field_name, &format!("{}: ", ts_optional_mark));
+                let [tagged, union, unwrapped] = ts_types;
+                ts_fields.push_choice(
+                    quote!(options.result),
+                    vec![
+                        (quote!(::struct_to_string::ResultStrategy::Tagged), tagged),
+                        (quote!(::struct_to_string::ResultStrategy::Union), union),
+                        (
+                            quote!(::struct_to_string::ResultStrategy::Unwrap),
+                            unwrapped,
+                        ),
+                    ],
+                );
+                ts_fields.push_str(";");
+                // Every integer becomes a `number`, so the width can be kept in a comment, as can
+                // the Rust name of a renamed field, to trace it back.
+                let integer = option_tokens(rust_integer_type(&field_type).as_deref());
+                let string_note = option_tokens(string_note.as_deref());
+                ts_fields.push_tokens(quote! {
+                    &::struct_to_string::__private::typescript_comment(
+                        options,
+                        #field_name,
+                        #rust_field_name,
+                        #integer,
+                        #string_note,
+                    )
+                });
+                ts_fields.push_str("\n");
+            }
+
+            // GraphQL resolvers, which read the field straight off the parent object.
+            graphql_resolvers.push_field(
+                "    ",
+                &field_name,
+                &format!(": (parent: {}) => parent.", ts_name),
+            );
+            graphql_resolvers.push_field("", &field_name, ",\n");
+
+            // Zod representation
+            let mut zod_type = rust_type_to_zod_type(&field_type);
+            if let Some(pattern) = &field_attributes.pattern {
+                zod_type = zod_type.replacen(
+                    "z.string()",
+                    &format!("z.string().regex(/{}/)", pattern.replace('/', "\\/")),
+                    1,
+                );
+            }
+            zod_fields.push_field(
+                "  ",
+                &field_name,
+                &format!(
+                    ": {}{},\n",
+                    zod_type,
+                    if is_optional { ".optional()" } else { "" }
+                ),
+            );
+
+            // Flow representation
+            flow_fields.push_field(
+                "  ",
+                &field_name,
+                &format!(
+                    ": {},{}\n",
+                    field_attributes.mapped_type("flow", &field_type, rust_type_to_flow_type),
+                    comment("//", &string_note)
+                ),
+            );
+
+            // OCaml representation
+            ocaml_fields.push_field(
+                "  ",
+                &field_name,
+                &format!(
+                    " : {};\n",
+                    field_attributes.mapped_type("ocaml", &field_type, rust_type_to_ocaml_type)
+                ),
+            );
+
+            // F# representation, with the record fields PascalCased.
+            fsharp_fields.push_str(&format!(
+                "    {}: {}\n",
+                pascal_case(&field_name),
+                field_attributes.mapped_type("fsharp", &field_type, rust_type_to_fsharp_type)
+            ));
+
+            // Elixir representation, the `defstruct` keys and the typespec of the struct.
+            let quote = elixir_atom_quote(&field_name);
+            elixir_keys.push_field(&format!(":{}", quote), &field_name, &format!("{}, ", quote));
+            elixir_fields.push_field(
+                &format!("    {}", quote),
+                &field_name,
+                &format!(
+                    "{}: {},\n",
+                    quote,
+                    field_attributes.mapped_type("elixir", &field_type, rust_type_to_elixir_type)
+                ),
+            );
+
+            // Python representation
+            let python_type =
+                field_attributes.mapped_type("python", &field_type, rust_type_to_python_type);
+            python_imports.extend(python_imports_for_type(&python_type));
+            python_fields.push_field(
+                "    ",
+                &field_name,
+                &format!(
+                    ": {}{}\n",
+                    python_type,
+                    // PEP 8 asks for two spaces before an inline comment.
+                    comment(" #", &string_note)
+                ),
+            );
+
+            python_none_default_fields.push_field(
+                "    ",
+                &field_name,
+                &format!(
+                    ": {}{}{}\n",
+                    field_attributes.mapped_type("python", &field_type, rust_type_to_python_type),
+                    if is_optional { " = None" } else { "" },
+                    comment(" #", &string_note)
+                ),
+            );
+
+            python_modern_fields.push_field(
+                "    ",
+                &field_name,
+                &format!(
+                    ": {}{}\n",
+                    field_attributes.mapped_type("python", &field_type, |ty| {
+                        rust_type_to_python_type_with_syntax(ty, PythonSyntax::Modern)
+                    }),
+                    comment(" #", &string_note)
+                ),
+            );
+
+            // Pydantic representation, where optional fields default to `None` so that they
+            // can be left out.
+            pydantic_fields.push_field(
+                "    ",
+                &field_name,
+                &format!(
+                    ": {}{}{}\n",
+                    field_attributes.mapped_type("python", &field_type, |ty| {
+                        rust_type_to_python_type_with_syntax(ty, PythonSyntax::BuiltinGenerics)
+                    }),
+                    if is_optional { " = None" } else { "" },
+                    comment(" #", &string_note)
+                ),
+            );
+
+            // Go representation
+            let (go_type, go_type_note) = match field_attributes.type_override("go") {
+                Some(type_override) => (type_override.to_string(), None),
+                None => rust_type_to_go_type(&field_type),
+            };
+            go_imports.extend(go_imports_for_type(&go_type));
+            // Unsupported types carry an explanation in a comment, which goes after the tags.
+            let go_type_comment = go_type_note
+                .map(|note| format!(" // {}", note))
+                .unwrap_or_default();
+            go_fields.push_field(
+                &format!(
+                    "    {} {} `json:\"",
+                    go_field_name(&rust_field_name),
+                    go_type
+                ),
+                &field_name,
+                &format!(
+                    "{}`{}{}\n",
+                    go_struct_tags(is_optional, &field_attributes),
+                    go_type_comment,
+                    comment("//", &note)
+                ),
+            );
+
+            // Java record representation, where the fields are the record's components.
+            java_record_components.push_field(
+                &format!(
+                    "{} ",
+                    field_attributes.mapped_type("java", &field_type, rust_type_to_java_type)
+                ),
+                &field_name,
+                ", ",
+            );
+
+            // Java representation, where the type depends on the builder's `java_collection` and
+            // `java_optional` options.
+            java_fields.push_str("    public ");
+            let mut java_types = Vec::new();
+            for (collection, collection_name) in [
+                (quote!(List), "List"),
+                (quote!(Stream), "Stream"),
+                (quote!(Iterable), "Iterable"),
+            ] {
+                for optional in [false, true] {
+                    let java_type = field_attributes.mapped_type("java", &field_type, |ty| {
+                        if optional {
+                            rust_type_to_java_optional_type(ty, collection_name)
+                        } else {
+                            rust_type_to_java_type_with_collection(ty, collection_name)
+                        }
+                    });
+                    java_types.push((
+                        quote!((::struct_to_string::JavaCollection::#collection, #optional)),
+                        java_type,
+                    ));
+                }
+            }
+            java_fields.push_choice(
+                quote!((options.java_collection, options.java_optional)),
+                java_types,
+            );
+            java_fields.push_field(" ", &field_name, &format!(";{}\n", comment("//", &note)));
+
+            let csharp_type =
+                field_attributes.mapped_type("csharp", &field_type, rust_type_to_csharp_type);
+            csharp_fields.push_field(
+                &format!("    public {} ", csharp_type),
+                &field_name,
+                &format!(";{}\n", comment("//", &note)),
+            );
+            // C# records and properties follow the .NET naming conventions instead.
+            csharp_record_parameters.push_str(&format!(
+                "{} {}, ",
+                csharp_type,
+                pascal_case(&rust_field_name)
+            ));
+            csharp_property_fields.push_str(&format!(
+                "    public {} {} {{ get; set; }}{}\n",
+                csharp_type,
+                pascal_case(&rust_field_name),
+                comment("//", &note)
+            ));
+
+            // Kotlin representation
+            let kotlin_type =
+                field_attributes.mapped_type("kotlin", &field_type, rust_type_to_kotlin_type);
+            kotlin_fields.push_str("    ");
+            kotlin_fields.push_tokens(quote!(if options.mutable { "var" } else { "val" }));
+            kotlin_fields.push_field(
+                " ",
+                &field_name,
+                &format!(": {},{}\n", kotlin_type, comment("//", &note)),
+            );
+
+            // Swift representation
+            let swift_type =
+                field_attributes.mapped_type("swift", &field_type, rust_type_to_swift_type);
+            swift_fields.push_str("    ");
+            swift_fields.push_tokens(quote!(if options.mutable { "var" } else { "let" }));
+            swift_fields.push_field(
+                " ",
+                &field_name,
+                &format!(": {}{}\n", swift_type, comment("//", &note)),
+            );
+
+            // C++ representation
+            cpp_fields.push_field(
+                &format!(
+                    "    {} ",
+                    field_attributes.mapped_type("cpp", &field_type, rust_type_to_cpp_type)
+                ),
+                &field_name,
+                &format!(";{}\n", comment("//", &note)),
+            );
+
+            // OpenAPI representation
+            openapi_properties.push_str(&format!("    {}:\n", field_name));
+            for line in rust_type_to_openapi_type(&field_type) {
+                openapi_properties.push_str(&format!("      {}\n", line));
+            }
+            if let Some(pattern) = &field_attributes.pattern {
+                openapi_properties.push_str(&format!(
+                    "      pattern: '{}'\n",
+                    pattern.replace('\'', "''")
+                ));
+            }
+            if let Some(format) = &field_attributes.format {
+                openapi_properties.push_str(&format!("      format: {}\n", format));
+            }
+            if !is_optional {
+                openapi_required.push_str(&format!("    - {}\n", field_name));
+            }
+            openapi_parameters.push_str(&format!(
+                "  - name: {}\n    in: query\n    required: {}\n    schema:\n",
+                field_name, !is_optional
+            ));
+            for line in rust_type_to_openapi_type(&field_type) {
+                openapi_parameters.push_str(&format!("      {}\n", line));
+            }
+            if let Some(pattern) = &field_attributes.pattern {
+                openapi_parameters.push_str(&format!(
+                    "      pattern: '{}'\n",
+                    pattern.replace('\'', "''")
+                ));
+            }
+            if let Some(format) = &field_attributes.format {
+                openapi_parameters.push_str(&format!("      format: {}\n", format));
+            }
+
+            // JSON Schema representation
+            let mut json_schema = rust_type_to_json_schema(strip_nested_options(&field_type));
+            if let Some(pattern) = &field_attributes.pattern {
+                json_schema.push(("pattern".to_string(), json_string(pattern)));
+            }
+            if let Some(format) = &field_attributes.format {
+                json_schema.push(("format".to_string(), json_string(format)));
+            }
+            json_schema_properties.push((field_name.clone(), json_object(&json_schema)));
+            if !is_optional {
+                json_schema_required.push(json_string(&field_name));
+            }
+
+            // Terraform representation, where a `null` default makes a variable optional.
+            hcl_variables.push(if is_optional {
+                format!(
+                    "variable \"{}\" {{\n  type    = {}\n  default = null\n}}",
+                    field_name,
+                    rust_type_to_hcl_type(strip_nested_options(&field_type))
+                )
+            } else {
+                format!(
+                    "variable \"{}\" {{\n  type = {}\n}}",
+                    field_name,
+                    rust_type_to_hcl_type(&field_type)
+                )
+            });
+
+            // TOML example, where the fields of other structs become tables, filled in once
+            // it's known whether their types derive `StructToString`.
+            let toml_comment = if is_optional { "# " } else { "" };
+            match (flattened, toml_table_type(&field_type, &type_params)) {
+                (Some(flattened), _) => toml_statements.push(quote! {
+                    if let Some((table_lines, table_tables)) =
+                        (&::core::marker::PhantomData::<#flattened>).toml_table(prefix, seen)
+                    {
+                        lines.push_str(&table_lines);
+                        tables.push_str(&table_tables);
+                    }
+                }),
+                (None, Some(table_type)) => toml_statements.push(quote! {
+                    {
+                        let path = if prefix.is_empty() {
+                            String::from(#field_name)
+                        } else {
+                            format!("{}.{}", prefix, #field_name)
+                        };
+                        let marker = &::core::marker::PhantomData::<#table_type>;
+                        match marker.toml_value() {
+                            Some(value) => lines.push_str(&format!("{}{} = {}\n", #toml_comment, #field_name, value)),
+                            None => {
+                                tables.push_str(&format!("\n[{}]\n", path));
+                                if let Some((table_lines, table_tables)) = marker.toml_table(&path, seen) {
+                                    tables.push_str(&table_lines);
+                                    tables.push_str(&table_tables);
+                                }
+                            }
+                        }
+                    }
+                }),
+                (None, None) => {
+                    let line = format!(
+                        "{}{} = {}\n",
+                        toml_comment,
+                        field_name,
+                        rust_type_to_toml_value(&field_type)
+                    );
+                    toml_statements.push(quote!(lines.push_str(#line);));
+                }
+            }
+
+            // CSV schema representation
+            match rust_type_to_csv_type(strip_nested_options(&field_type)) {
+                Some(csv_type) => csv_columns.push_str(&format!("{},{}\n", field_name, csv_type)),
+                None => csv_errors.push(format!(
+                    "field `{}` of type `{}` is not a flat CSV column",
+                    field_name, field_type_tokens
+                )),
+            }
+        }
+    }
+    let hcl_variables = hcl_variables.join("\n\n");
+    let csv_schema = if csv_errors.is_empty() {
+        quote!(Ok(String::from(#csv_columns)))
+    } else {
+        let csv_errors = csv_errors.join("; ");
+        quote!(Err(String::from(#csv_errors)))
+    };
+
+    let openapi_properties = if openapi_properties.is_empty() {
+        String::from("  properties: {}\n")
+    } else {
+        format!("  properties:\n{}", openapi_properties)
+    };
+    if !openapi_required.is_empty() {
+        openapi_required.insert_str(0, "  required:\n");
+    }
+    // The table is named like the struct in snake case, with a `?` placeholder per column.
+    let sql_insert = format!(
+        "INSERT INTO {} ({}) VALUES ({});",
+        screaming_snake_case(&name.to_string()).to_lowercase(),
+        field_names.join(", "),
+        vec!["?"; field_names.len()].join(", ")
+    );
+
+    let openapi_parameters = if openapi_parameters.is_empty() {
+        String::from("parameters: []")
+    } else {
+        format!("parameters:\n{}", openapi_parameters.trim_end())
+    };
+
+    let mut json_schema = vec![
+        (
+            "$schema".to_string(),
+            json_string("https://json-schema.org/draft/2020-12/schema"),
+        ),
+        ("title".to_string(), json_string(&name.to_string())),
+        ("type".to_string(), json_string("object")),
+        (
+            "properties".to_string(),
+            json_object(&json_schema_properties),
+        ),
+    ];
+    if !json_schema_required.is_empty() {
+        json_schema.push((
+            "required".to_string(),
+            format!("[{}]", json_schema_required.join(", ")),
+        ));
+    }
+    let json_schema = json_object(&json_schema);
+
+    if let Some(indent) = &container_attributes.indent {
+        rust_fields = reindent(&rust_fields, "    ", indent);
+        rust_short_fields = reindent(&rust_short_fields, "    ", indent);
+        rust_attr_fields = reindent(&rust_attr_fields, "    ", indent);
+        for field_list in field_lists!().into_iter().chain([&mut ts_fields]) {
+            field_list.reindent(indent);
+        }
+    }
+
+    let rust_new_impl = format!(
+        "{} {{\n    pub fn new({}) -> Self {{\n        Self {{ {} }}\n    }}\n}}",
+        rust_impl_header(&name, &ast.generics, ""),
+        rust_new_params.join(", "),
+        rust_new_fields.join(", ")
+    );
+
+    // Without a `display` template, every field is written on its own line with `Debug`.
+    let (display_bound, display_body) = match &container_attributes.display {
+        Some(template) => {
+            let (format, arguments) = match display_template(template, &rust_new_fields) {
+                Ok(display_template) => display_template,
+                Err(err) => return err.to_compile_error().into(),
+            };
+            (
+                quote!(std::fmt::Display),
+                format!(
+                    "        write!(f, {:?}{})\n",
+                    format,
+                    arguments
+                        .iter()
+                        .map(|field| format!(", self.{}", field))
+                        .collect::<String>()
+                ),
+            )
+        }
+        None => (
+            quote!(std::fmt::Debug),
+            format!(
+                "{}        Ok(())\n",
+                rust_new_fields
+                    .iter()
+                    .map(|field| format!(
+                        "        writeln!(f, \"{0}: {{:?}}\", self.{0})?;\n",
+                        field
+                    ))
+                    .collect::<String>()
+            ),
+        ),
+    };
+    // Every type parameter is written with the trait, on top of its own bounds.
+    let mut display_generics = ast.generics.clone();
+    for type_param in display_generics.type_params_mut() {
+        type_param.bounds.push(syn::parse_quote!(#display_bound));
+    }
+    let rust_display_impl = format!(
+        "{} {{\n    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{\n{}    }}\n}}",
+        rust_impl_header(&name, &display_generics, "std::fmt::Display for "),
+        display_body
+    );
+
+    let go_imports = match go_imports.len() {
+        0 => String::new(),
+        1 => format!("import \"{}\"\n\n", go_imports.first().unwrap()),
+        _ => format!(
+            "import (\n{})\n\n",
+            go_imports
+                .iter()
+                .map(|import| format!("    \"{}\"\n", import))
+                .collect::<String>()
+        ),
+    };
+
+    // The standard library imports come first, plain `import`s before `from` ones as isort
+    // orders them, then `dataclasses_json`.
+    python_imports.insert(("dataclasses", "dataclass"));
+    let mut python_module_header = String::new();
+    let mut from_imports = std::collections::BTreeMap::<_, Vec<_>>::new();
+    for (module, name) in python_imports {
+        if name.is_empty() {
+            python_module_header.push_str(&format!("import {}\n", module));
+        } else {
+            from_imports.entry(module).or_default().push(name);
+        }
+    }
+    for (module, names) in from_imports {
+        python_module_header.push_str(&format!("from {} import {}\n", module, names.join(", ")));
+    }
+    python_module_header.push_str("\nfrom dataclasses_json import dataclass_json\n\n\n");
+
+    let flattened_fields_arms: Vec<_> = field_lists!()
+        .into_iter()
+        .map(|field_list| {
+            let kind = field_list.kind;
+            quote!(#kind => Some(#field_list))
+        })
+        .collect();
+    let toml_methods = quote! {
+        fn toml_table(
+            prefix: &str,
+            seen: &mut Vec<&'static str>,
+        ) -> Option<(String, String)> {
+            // A struct nested in itself, even indirectly, is left as an empty table.
+            let type_name = ::core::any::type_name::<Self>();
+            if seen.contains(&type_name) {
+                return None;
+            }
+            seen.push(type_name);
+            let mut lines = String::new();
+            #[allow(unused_mut)]
+            let mut tables = String::new();
+            {
+                #[allow(unused_imports)]
+                use ::struct_to_string::__private::{ViaDerive as _, ViaFallback as _};
+                let _ = prefix;
+                #(#toml_statements)*
+            }
+            seen.pop();
+            Some((lines, tables))
+        }
+    };
+    // The builder's options are read by the methods writing the field lists, which every
+    // language but Rust has.
+    let options_arms = [
+        ("go", quote!(Self::go_string(options))),
+        ("python", quote!(Self::python_string(false, options))),
+        (
+            "typescript",
+            quote!(Self::typescript_string(false, options)),
+        ),
+        ("java", quote!(Self::java_string(options))),
+        ("csharp", quote!(Self::csharp_string("public", options))),
+        ("kotlin", quote!(Self::kotlin_string(options))),
+        ("swift", quote!(Self::swift_string(options))),
+        ("cpp", quote!(Self::cpp_string(options))),
+        ("flow", quote!(Self::flow_string(options))),
+        ("elixir", quote!(Self::elixir_string(options))),
+        ("ocaml", quote!(Self::ocaml_string(options))),
+        ("fsharp", quote!(Self::fsharp_string(options))),
+    ];
+    let shared_impls = shared_impls(
+        &name,
+        &ast.generics,
+        &referenced_types,
+        Some(StructParts {
+            flattened_fields_arms: &flattened_fields_arms,
+            field_names: &field_names,
+            options_arms: &options_arms,
+            indent: container_attributes.indent.as_deref(),
+        }),
+        toml_methods,
+        shape_hash,
+    );
+
+    let rust_methods = language_methods(
+        "rust",
+        quote! {
+            pub fn to_rust_string() -> String {
+                Self::to_rust_string_with_derive(false)
+            }
+
+            /// Like `to_rust_string`, optionally annotating the struct with
+            /// `#[derive(StructToString)]` so the generated code is itself convertible.
+            pub fn to_rust_string_with_derive(derive: bool) -> String {
+                let mut res = String::new();
+                if derive {
+                    res.push_str("#[derive(StructToString)]\n");
+                }
+                res.push_str(#rust_struct_keyword);
+                res.push_str(#angle_bracketed_name);
+                res.push_str(" {\n");
+                res.push_str(#rust_fields.trim_end_matches(",\n"));
+                res.push_str("\n}");
+                res
+            }
+
+            /// Like `to_rust_string`, but reproducing the original definition: the struct's
+            /// and the fields' attributes, such as doc comments and `#[derive(...)]`s, are kept,
+            /// as are the lifetimes, bounds and where clause. The `#[derive(...)]` naming
+            /// `StructToString` itself never reaches the macro.
+            pub fn to_rust_string_with_attrs() -> String {
+                let mut res = String::from(#rust_attrs);
+                res.push_str(#rust_struct_keyword);
+                res.push_str(#rust_full_name);
+                res.push_str(" {\n");
+                res.push_str(#rust_attr_fields.trim_end_matches(",\n"));
+                res.push_str("\n}");
+                res
+            }
+
+            /// Like `to_rust_string`, optionally shortening paths such as
+            /// `crate::models::Address` to `Address`, the name every other language uses.
+            pub fn to_rust_string_with_short_names(short_names: bool) -> String {
+                if !short_names {
+                    return Self::to_rust_string();
+                }
+                let mut res = String::from(#rust_struct_keyword);
+                res.push_str(#angle_bracketed_name);
+                res.push_str(" {\n");
+                res.push_str(#rust_short_fields.trim_end_matches(",\n"));
+                res.push_str("\n}");
+                res
+            }
+
+            pub fn to_rust_new_impl_string() -> String {
+                String::from(#rust_new_impl)
+            }
+
+            /// Scaffolds an `impl std::fmt::Display` block writing the struct's `display`
+            /// template, or one line per field without one.
+            pub fn to_rust_display_impl_string() -> String {
+                String::from(#rust_display_impl)
+            }
+        },
+    );
+
+    let go_methods = language_methods(
+        "go",
+        quote! {
+            pub fn to_go_string() -> String {
+                Self::go_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn go_string(options: &::struct_to_string::__private::Options) -> String {
+                let mut res = String::from("type ");
+                res.push_str(#go_name);
+                res.push_str(" struct {\n");
+                res.push_str(&#go_fields);
+                res.push_str("}");
+                res
+            }
+
+            /// Like `to_go_string`, but emits a complete Go file with a `package` clause and
+            /// the imports the field types need.
+            pub fn to_go_string_with_package(package: &str) -> String {
+                let mut res = String::from("package ");
+                res.push_str(package);
+                res.push_str("\n\n");
+                res.push_str(#go_imports);
+                res.push_str(&Self::to_go_string());
+                res
+            }
+        },
+    );
+
+    let python_methods = language_methods(
+        "python",
+        quote! {
+            pub fn to_python_string() -> String {
+                Self::to_python_string_with_none_defaults(false)
+            }
+
+            /// Like `to_python_string`, optionally giving `Optional` fields a `= None` default
+            /// so they can be left out. The dataclass is then keyword-only, since fields with
+            /// a default can't otherwise precede fields without one.
+            pub fn to_python_string_with_none_defaults(none_defaults: bool) -> String {
+                Self::python_string(none_defaults, &::struct_to_string::__private::Options::default())
+            }
+
+            fn python_string(
+                none_defaults: bool,
+                options: &::struct_to_string::__private::Options,
+            ) -> String {
+                let mut res = String::from("@dataclass_json\n");
+                res.push_str(if none_defaults {
+                    "@dataclass(kw_only=True)\nclass "
+                } else {
+                    "@dataclass\nclass "
+                });
+                res.push_str(#python_name);
+                res.push_str(":\n");
+                res.push_str(&if none_defaults {
+                    #python_none_default_fields
+                } else {
+                    #python_fields
+                });
+                res
+            }
+
+            /// Like `to_python_string`, preceded by the imports it needs, so that it's a
+            /// complete Python module. Only the imports of the type's own fields are known, so
+            /// those of `#[serde(flatten)]` fields' types have to be added by hand.
+            pub fn to_python_module_string() -> String {
+                let mut res = String::from(#python_module_header);
+                res.push_str(&Self::to_python_string());
+                res
+            }
+
+            /// Like `to_python_string`, but with the typing syntax of Python 3.10 and later, e.g.
+            /// `list[int]` and `int | None`, which needs no `typing` imports.
+            pub fn to_python_string_modern() -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::from("@dataclass_json\n@dataclass\nclass ");
+                res.push_str(#python_name);
+                res.push_str(":\n");
+                res.push_str(&#python_modern_fields);
+                res
+            }
+
+            /// Like `to_python_string`, but declares a Pydantic v2 model, using the builtin
+            /// `list` and `dict` generics.
+            pub fn to_pydantic_string() -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::from("class ");
+                res.push_str(#python_name);
+                res.push_str("(BaseModel):\n");
+                res.push_str(&#pydantic_fields);
+                res
+            }
+        },
+    );
+
+    // An interface extends the flattened types, which a type alias intersects with instead.
+    let ts_extends_clause = if ts_extends.is_empty() {
+        String::new()
+    } else {
+        format!(" extends {}", ts_extends.join(", "))
+    };
+    let ts_intersection: String = ts_extends
+        .iter()
+        .map(|extended| format!("{} & ", extended))
+        .collect();
+    let typescript_methods = language_methods(
+        "typescript",
+        quote! {
+            pub fn to_typescript_string() -> String {
+                Self::to_typescript_string_with_export(false)
+            }
+
+            /// Like `to_typescript_string`, optionally prefixing the interface with `export`.
+            pub fn to_typescript_string_with_export(export: bool) -> String {
+                Self::typescript_string(export, &::struct_to_string::__private::Options::default())
+            }
+
+            fn typescript_string(
+                export: bool,
+                options: &::struct_to_string::__private::Options,
+            ) -> String {
+                let mut res = String::new();
+                if export {
+                    res.push_str("export ");
+                }
+                res.push_str("interface ");
+                res.push_str(#ts_name);
+                res.push_str(#ts_extends_clause);
+                res.push_str(" {\n");
+                res.push_str(&#ts_fields);
+                res.push_str("}");
+                res
+            }
+
+            /// Like `to_typescript_string`, but declares a `type` alias instead of an `interface`,
+            /// optionally prefixed with `export`.
+            pub fn to_typescript_type_alias_string(export: bool) -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::new();
+                if export {
+                    res.push_str("export ");
+                }
+                res.push_str("type ");
+                res.push_str(#ts_name);
+                res.push_str(" = ");
+                res.push_str(#ts_intersection);
+                res.push_str("{\n");
+                res.push_str(&#ts_fields);
+                res.push_str("};");
+                res
+            }
+
+            /// Scaffolds a GraphQL resolver map in TypeScript, with a resolver per field that
+            /// returns it from the parent object.
+            pub fn to_graphql_resolver_string() -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::from("const ");
+                res.push_str(#ts_value_name);
+                res.push_str("Resolvers = {\n");
+                res.push_str(&#graphql_resolvers);
+                res.push_str("};");
+                res
+            }
+
+            /// Declares a Zod schema validating the struct at runtime. Fields of custom types
+            /// refer to the schema of the same name.
+            pub fn to_zod_string() -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::from("export const ");
+                res.push_str(#ts_value_name);
+                res.push_str(" = z.object({\n");
+                res.push_str(&#zod_fields);
+                res.push_str("});");
+                res
+            }
+        },
+    );
+
+    let flow_methods = language_methods(
+        "flow",
+        quote! {
+            pub fn to_flow_string() -> String {
+                Self::flow_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn flow_string(options: &::struct_to_string::__private::Options) -> String {
+                let mut res = String::from("type ");
+                res.push_str(#flow_name);
+                res.push_str(" = {|\n");
+                res.push_str(&#flow_fields);
+                res.push_str("|};");
+                res
+            }
+        },
+    );
+
+    let elixir_methods = language_methods(
+        "elixir",
+        quote! {
+            pub fn to_elixir_string() -> String {
+                Self::elixir_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn elixir_string(options: &::struct_to_string::__private::Options) -> String {
+                let keys = #elixir_keys;
+                let fields = #elixir_fields;
+                let mut res = String::from("defmodule ");
+                res.push_str(#elixir_name);
+                res.push_str(" do\n");
+                res.push_str(#elixir_indent);
+                res.push_str("defstruct [");
+                res.push_str(keys.trim_end_matches(", "));
+                res.push_str("]\n\n");
+                res.push_str(#elixir_indent);
+                res.push_str("@type t :: %__MODULE__{");
+                if !fields.is_empty() {
+                    res.push('\n');
+                    res.push_str(fields.trim_end_matches(",\n"));
+                    res.push('\n');
+                    res.push_str(#elixir_indent);
+                }
+                res.push_str("}\nend");
+                res
+            }
+        },
+    );
+
+    let ocaml_methods = language_methods(
+        "ocaml",
+        quote! {
+            pub fn to_ocaml_string() -> String {
+                Self::ocaml_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn ocaml_string(options: &::struct_to_string::__private::Options) -> String {
+                let fields = #ocaml_fields;
+                let mut res = String::from("type ");
+                res.push_str(#ocaml_name);
+                // A record needs at least one field.
+                if fields.is_empty() {
+                    res.push_str(" = unit");
+                } else {
+                    res.push_str(" = {\n");
+                    res.push_str(&fields);
+                    res.push('}');
+                }
+                res
+            }
+        },
+    );
+
+    let fsharp_methods = language_methods(
+        "fsharp",
+        quote! {
+            pub fn to_fsharp_string() -> String {
+                Self::fsharp_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn fsharp_string(options: &::struct_to_string::__private::Options) -> String {
+                let fields = #fsharp_fields;
+                let mut res = String::from("type ");
+                res.push_str(#fsharp_name);
+                // A record needs at least one field.
+                if fields.is_empty() {
+                    res.push_str(" = unit");
+                } else {
+                    res.push_str(" = {\n");
+                    res.push_str(&fields);
+                    res.push('}');
+                }
+                res
+            }
+        },
+    );
+
+    let java_methods = language_methods(
+        "java",
+        quote! {
+            pub fn to_java_string() -> String {
+                Self::java_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn java_string(options: &::struct_to_string::__private::Options) -> String {
+                let mut res = String::from("public class ");
+                res.push_str(#java_name);
+                res.push_str(" {\n");
+                res.push_str(&#java_fields);
+                res.push_str("}");
+                res
+            }
+
+            /// Like `to_java_string`, but declares an immutable `record` instead of a class
+            /// with public fields.
+            pub fn to_java_record_string() -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::from("public record ");
+                res.push_str(#java_name);
+                res.push('(');
+                res.push_str(#java_record_components.trim_end_matches(", "));
+                res.push_str(") {}");
+                res
+            }
+        },
+    );
+
+    let csharp_methods = language_methods(
+        "csharp",
+        quote! {
+            pub fn to_csharp_string() -> String {
+                Self::to_csharp_string_with_access_modifier("public")
+            }
+
+            /// Like `to_csharp_string`, but declares the class with the given access modifier,
+            /// e.g. `internal`.
+            pub fn to_csharp_string_with_access_modifier(access_modifier: &str) -> String {
+                Self::csharp_string(access_modifier, &::struct_to_string::__private::Options::default())
+            }
+
+            fn csharp_string(
+                access_modifier: &str,
+                options: &::struct_to_string::__private::Options,
+            ) -> String {
+                let mut res = String::from(access_modifier);
+                res.push_str(" class ");
+                res.push_str(#csharp_name);
+                res.push_str(" {\n");
+                res.push_str(&#csharp_fields);
+                res.push_str("}");
+                res
+            }
+
+            /// Like `to_csharp_string`, but declares a positional `record`, e.g.
+            /// `public record User(long Id, string Name);`.
+            pub fn to_csharp_record_string() -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::from("public record ");
+                res.push_str(#csharp_name);
+                res.push('(');
+                res.push_str(#csharp_record_parameters.trim_end_matches(", "));
+                res.push_str(");");
+                res
+            }
+
+            /// Like `to_csharp_string`, but declares auto-properties instead of public fields,
+            /// e.g. `public long Id { get; set; }`.
+            pub fn to_csharp_property_string() -> String {
+                let options = &::struct_to_string::__private::Options::default();
+                let mut res = String::from("public class ");
+                res.push_str(#csharp_name);
+                res.push_str(" {\n");
+                res.push_str(&#csharp_property_fields);
+                res.push_str("}");
+                res
+            }
+        },
+    );
+
+    let kotlin_methods = language_methods(
+        "kotlin",
+        quote! {
+            pub fn to_kotlin_string() -> String {
+                Self::kotlin_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn kotlin_string(options: &::struct_to_string::__private::Options) -> String {
+                let mut res = String::from("data class ");
+                res.push_str(#kotlin_name);
+                res.push_str("(\n");
+                res.push_str(&#kotlin_fields);
+                res.push_str(")");
+                res
+            }
+        },
+    );
+
+    let swift_methods = language_methods(
+        "swift",
+        quote! {
+            pub fn to_swift_string() -> String {
+                Self::swift_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn swift_string(options: &::struct_to_string::__private::Options) -> String {
+                let mut res = String::from("struct ");
+                res.push_str(#swift_name);
+                res.push_str(" {\n");
+                res.push_str(&#swift_fields);
+                res.push_str("}");
+                res
+            }
+        },
+    );
+
+    let cpp_methods = language_methods(
+        "cpp",
+        quote! {
+            pub fn to_cpp_string() -> String {
+                Self::cpp_string(&::struct_to_string::__private::Options::default())
+            }
+
+            fn cpp_string(options: &::struct_to_string::__private::Options) -> String {
+                let mut res = String::from(#cpp_template);
+                res.push_str("struct ");
+                res.push_str(#cpp_name);
+                res.push_str(" {\n");
+                res.push_str(&#cpp_fields);
+                res.push_str("};");
+                res
+            }
+        },
+    );
+
+    let gen = quote! {
+        #shared_impls
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #rust_methods
+            #go_methods
+            #python_methods
+            #typescript_methods
+            #java_methods
+            #csharp_methods
+            #kotlin_methods
+            #swift_methods
+            #cpp_methods
+            #flow_methods
+            #elixir_methods
+            #ocaml_methods
+            #fsharp_methods
+
+            /// Describes the struct as CSV columns, one `column_name,type` line per field, with
+            /// SQL-like type names. Fails for fields that don't fit in a single CSV column,
+            /// such as collections or nested structs.
+            pub fn to_csv_schema_string() -> Result<String, String> {
+                #csv_schema
+            }
+
+            /// Scaffolds a parameterized `INSERT` of the struct into a table named like it in
+            /// snake case, e.g. `INSERT INTO user_account (id, name) VALUES (?, ?);`. The columns
+            /// of `#[serde(flatten)]` fields aren't known, so they're left out.
+            pub fn to_sql_insert_string() -> String {
+                String::from(#sql_insert)
+            }
+
+            /// Scaffolds an example TOML config of the struct, with a placeholder value for each
+            /// field. Fields of structs that derive `StructToString` too become `[parent.child]`
+            /// tables filled in the same way, other custom types an empty table, and optional
+            /// fields are commented out, as TOML has no null.
+            pub fn to_toml_example_string() -> String {
+                let (mut res, tables) =
+                    <Self as ::struct_to_string::__private::CollectDefinitions>::toml_table(
+                        "",
+                        &mut Vec::new(),
+                    )
+                    .unwrap_or_default();
+                res.push_str(&tables);
+                res.trim_matches('\n').to_string()
+            }
+
+            /// Declares each field as a Terraform `variable` block.
+            pub fn to_hcl_string() -> String {
+                String::from(#hcl_variables)
+            }
+
+            /// Describes the struct as a JSON Schema (draft 2020-12) document. Custom field types
+            /// are referenced as `#/$defs/<Name>`.
+            pub fn to_json_schema_string() -> String {
+                String::from(#json_schema)
+            }
+
+            pub fn to_openapi_schema_string() -> String {
+                let mut res = String::from(stringify!(#name));
+                res.push_str(":\n  type: object\n");
+                res.push_str(#openapi_properties);
+                res.push_str(#openapi_required);
+                res
+            }
+
+            /// Describes the fields as the `parameters` of an OpenAPI 3 operation taking them in
+            /// the query string, e.g. for a struct extracted from the query. Optional fields
+            /// aren't `required`.
+            pub fn to_openapi_parameters_string() -> String {
+                String::from(#openapi_parameters)
+            }
+        }
+    };
+
+    gen.into()
+}
+
+/// Generates the implementation for a newtype, a tuple struct with a single field such as
+/// `struct UserId(u64)`. It serializes as its field, so it becomes an alias of the field's type
+/// wherever the language has them, and a class wrapping a `value` otherwise.
+fn newtype_to_string(
+    ast: &DeriveInput,
+    field: &syn::Field,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &ast.ident;
+    let name_string = name.to_string();
+    let container_attributes = ContainerAttributes::from_attrs(&ast.attrs)?;
+    if let Some(display) = &container_attributes.display {
+        return Err(syn::Error::new_spanned(
+            display,
+            "`display` is only supported on structs with named fields",
+        ));
+    }
+    let field_attributes = FieldAttributes::from_attrs(&field.attrs)?;
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let type_params: Vec<String> = ast
+        .generics
+        .type_params()
+        .map(|type_param| type_param.ident.to_string())
+        .collect();
+    let decorated_name = |language: &str, open: &str, close: &str| {
+        let name = container_attributes.decorated_name(language, &name_string);
+        generic_type_name(&name, &type_params, open, close)
+    };
+
+    let field_type = &field.ty;
+    let field_type_tokens = rust_type_string(field_type);
+    let mut referenced_types = Vec::new();
+    collect_referenced_types(field_type, &type_params, &mut referenced_types);
+    let field_type = if field_attributes.serde_bytes {
+        byte_vec_as_byte_buf(field_type)
+    } else if field_attributes.pattern.is_some() || field_attributes.format.is_some() {
+        constrained_string(field_type)
+    } else {
+        string_like_as_string(&nonzero_as_integer(field_type))
+    };
+    let mapped_type = |language: &str, convert: fn(&Type) -> String| {
+        field_attributes.mapped_type(language, &field_type, convert)
+    };
+
+    let rust_string = format!(
+        "{}struct {}({}{});",
+        visibility_to_string(&ast.vis),
+        generic_type_name(&name_string, &type_params, "<", ">"),
+        visibility_to_string(&field.vis),
+        field_attributes
+            .type_override("rust")
+            .unwrap_or(&field_type_tokens)
+    );
+    // `NewType` can't be generic, so the type parameters are left out.
+    let python_name = container_attributes.decorated_name("python", &name_string);
+    let python_string = format!(
+        "{} = NewType(\"{}\", {})",
+        python_name,
+        python_name,
+        mapped_type("python", rust_type_to_python_type)
+    );
+    let ts_string = format!(
+        "type {} = {};",
+        decorated_name("typescript", "<", ">"),
+        mapped_type("typescript", rust_type_to_ts_type)
+    );
+    let flow_string = format!(
+        "type {} = {};",
+        decorated_name("flow", "<", ">"),
+        mapped_type("flow", rust_type_to_flow_type)
+    );
+    let elixir_string = format!(
+        "defmodule {} do\n  @type t :: {}\nend",
+        container_attributes.decorated_name("elixir", &name_string),
+        mapped_type("elixir", rust_type_to_elixir_type)
+    );
+    let ocaml_string = format!(
+        "type {} = {}",
+        ocaml_type_name(&container_attributes.decorated_name("ocaml", &name_string)),
+        mapped_type("ocaml", rust_type_to_ocaml_type)
+    );
+    let fsharp_string = format!(
+        "type {} = {}",
+        container_attributes.decorated_name("fsharp", &name_string),
+        mapped_type("fsharp", rust_type_to_fsharp_type)
+    );
+    let go_type_params: Vec<String> = type_params
+        .iter()
+        .map(|type_param| format!("{} any", type_param))
+        .collect();
+    let go_string = format!(
+        "type {} {}",
+        generic_type_name(
+            &container_attributes.decorated_name("go", &name_string),
+            &go_type_params,
+            "[",
+            "]"
+        ),
+        mapped_type("go", |ty| match rust_type_to_go_type(ty) {
+            (go_type, Some(note)) => format!("{} // {}", go_type, note),
+            (go_type, None) => go_type,
+        })
+    );
+    let java_string = format!(
+        "public class {} {{\n    public {} value;\n}}",
+        decorated_name("java", "<", ">"),
+        mapped_type("java", rust_type_to_java_type)
+    );
+    let csharp_string = format!(
+        "public class {} {{\n    public {} value;\n}}",
+        decorated_name("csharp", "<", ">"),
+        mapped_type("csharp", rust_type_to_csharp_type)
+    );
+    let kotlin_string = format!(
+        "typealias {} = {}",
+        decorated_name("kotlin", "<", ">"),
+        mapped_type("kotlin", rust_type_to_kotlin_type)
+    );
+    let swift_string = format!(
+        "typealias {} = {}",
+        decorated_name("swift", "<", ">"),
+        mapped_type("swift", rust_type_to_swift_type)
+    );
+    let cpp_template = match type_params.len() {
+        0 => String::new(),
+        _ => format!(
+            "template <{}>\n",
+            type_params
+                .iter()
+                .map(|type_param| format!("typename {}", type_param))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+    };
+    let cpp_string = format!(
+        "{}using {} = {};",
+        cpp_template,
+        container_attributes.decorated_name("cpp", &name_string),
+        mapped_type("cpp", rust_type_to_cpp_type)
+    );
+
+    // A newtype is written as its field, when that isn't a custom type.
+    let toml_methods = match toml_table_type(&field_type, &type_params) {
+        Some(_) => quote!(),
+        None => {
+            let toml_value = rust_type_to_toml_value(&field_type);
+            quote! {
+                fn toml_value() -> Option<String> {
+                    Some(String::from(#toml_value))
+                }
+            }
+        }
+    };
+    let shared_impls = shared_impls(
+        name,
+        &ast.generics,
+        &referenced_types,
+        None,
+        toml_methods,
+        schema_hash(&ast.data, container_attributes.rename_all)?,
+    );
+    let methods = [
+        ("typescript", quote!(to_typescript_string), ts_string),
+        ("python", quote!(to_python_string), python_string),
+        ("go", quote!(to_go_string), go_string),
+        ("java", quote!(to_java_string), java_string),
+        ("csharp", quote!(to_csharp_string), csharp_string),
+        ("kotlin", quote!(to_kotlin_string), kotlin_string),
+        ("swift", quote!(to_swift_string), swift_string),
+        ("cpp", quote!(to_cpp_string), cpp_string),
+        ("flow", quote!(to_flow_string), flow_string),
+        ("elixir", quote!(to_elixir_string), elixir_string),
+        ("ocaml", quote!(to_ocaml_string), ocaml_string),
+        ("fsharp", quote!(to_fsharp_string), fsharp_string),
+    ]
+    .into_iter()
+    .map(|(language, method, string)| {
+        language_methods(
+            language,
+            quote! {
+                pub fn #method() -> String {
+                    String::from(#string)
+                }
+            },
+        )
+    });
+    let rust_methods = language_methods(
+        "rust",
+        quote! {
+            pub fn to_rust_string() -> String {
+                Self::to_rust_string_with_derive(false)
+            }
+
+            /// Like `to_rust_string`, optionally annotating the struct with
+            /// `#[derive(StructToString)]` so the generated code is itself convertible.
+            pub fn to_rust_string_with_derive(derive: bool) -> String {
+                let mut res = String::new();
+                if derive {
+                    res.push_str("#[derive(StructToString)]\n");
+                }
+                res.push_str(#rust_string);
+                res
+            }
+        },
+    );
+
+    Ok(quote! {
+        #shared_impls
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #rust_methods
+            #(#methods)*
+        }
+    })
+}
+
+/// Generates the implementation for a `#[serde(untagged)]` enum whose variants carry data.
+/// Such an enum serializes as the data of whichever variant it holds, so it becomes a plain
+/// union of the variants' shapes where the language has unions (TypeScript, Flow, Python and
+/// Elixir), with a unit variant serializing as `null`. The other languages get their top type.
+fn untagged_enum_to_string(
+    ast: &DeriveInput,
+    data_enum: &syn::DataEnum,
+    container_attributes: &ContainerAttributes,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &ast.ident;
+    let name_string = name.to_string();
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+    let type_params: Vec<String> = ast
+        .generics
+        .type_params()
+        .map(|type_param| type_param.ident.to_string())
+        .collect();
+    let decorated_name = |language: &str| {
+        let name = container_attributes.decorated_name(language, &name_string);
+        generic_type_name(&name, &type_params, "<", ">")
+    };
+
+    let mut rust_variants = Vec::new();
+    let mut ts_variants = Vec::new();
+    let mut flow_variants = Vec::new();
+    let mut elixir_variants = Vec::new();
+    let mut python_variants = Vec::new();
+    let mut referenced_types = Vec::new();
+    for variant in &data_enum.variants {
+        let variant_attributes = FieldAttributes::from_attrs(&variant.attrs)?;
+        let field_type_tokens = |field: &syn::Field| rust_type_string(&field.ty);
+        for field in &variant.fields {
+            collect_referenced_types(&field.ty, &type_params, &mut referenced_types);
+        }
+
+        // Rust representation
+        rust_variants.push(match &variant.fields {
+            syn::Fields::Unit => format!("    {}", variant.ident),
+            syn::Fields::Unnamed(fields) => format!(
+                "    {}({})",
+                variant.ident,
+                fields
+                    .unnamed
+                    .iter()
+                    .map(field_type_tokens)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            ),
+            syn::Fields::Named(fields) => format!(
+                "    {} {{ {} }}",
+                variant.ident,
+                fields
+                    .named
+                    .iter()
+                    .map(|field| format!(
+                        "{}: {}",
+                        field.ident.as_ref().unwrap(),
+                        field_type_tokens(field)
+                    ))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        });
+
+        if variant_attributes.skip {
+            continue;
+        }
+        match &variant.fields {
+            syn::Fields::Unit => {
+                ts_variants.push("null".to_string());
+                flow_variants.push("null".to_string());
+                elixir_variants.push("nil".to_string());
+                python_variants.push("None".to_string());
+            }
+            syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                let ty = &fields.unnamed[0].ty;
+                ts_variants.push(rust_type_to_ts_type(ty));
+                flow_variants.push(rust_type_to_flow_type(ty));
+                elixir_variants.push(rust_type_to_elixir_type(ty));
+                python_variants.push(rust_type_to_python_type(ty));
+            }
+            syn::Fields::Unnamed(fields) => {
+                let ty: Type = syn::Type::Tuple(syn::TypeTuple {
+                    paren_token: Default::default(),
+                    elems: fields
+                        .unnamed
+                        .iter()
+                        .map(|field| field.ty.clone())
+                        .collect(),
+                });
+                ts_variants.push(rust_type_to_ts_type(&ty));
+                flow_variants.push(rust_type_to_flow_type(&ty));
+                elixir_variants.push(rust_type_to_elixir_type(&ty));
+                python_variants.push(rust_type_to_python_type(&ty));
+            }
+            syn::Fields::Named(fields) => {
+                let mut ts_fields = Vec::new();
+                let mut flow_fields = Vec::new();
+                let mut elixir_fields = Vec::new();
+                for field in &fields.named {
+                    let field_attributes = FieldAttributes::from_attrs(&field.attrs)?;
+                    if field_attributes.skip {
+                        continue;
+                    }
+                    let field_name = field_attributes
+                        .rename
+                        .clone()
+                        .unwrap_or_else(|| field.ident.as_ref().unwrap().to_string());
+                    ts_fields.push(format!(
+                        "{}: {}",
+                        field_name,
+                        field_attributes.mapped_type("typescript", &field.ty, rust_type_to_ts_type)
+                    ));
+                    flow_fields.push(format!(
+                        "{}: {}",
+                        field_name,
+                        field_attributes.mapped_type("flow", &field.ty, rust_type_to_flow_type)
+                    ));
+                    elixir_fields.push(format!(
+                        "{} {}",
+                        elixir_key(&field_name),
+                        field_attributes.mapped_type("elixir", &field.ty, rust_type_to_elixir_type)
+                    ));
+                }
+                ts_variants.push(format!("{{ {} }}", ts_fields.join("; ")));
+                flow_variants.push(format!("{{| {} |}}", flow_fields.join(", ")));
+                elixir_variants.push(format!("%{{{}}}", elixir_fields.join(", ")));
+                // Python has no anonymous record type.
+                python_variants.push("Dict[str, Any]".to_string());
+            }
+        }
+    }
+
+    let rust_string = format!(
+        "{}{}enum {} {{\n{}\n}}",
+        repr_attributes_to_string(&ast.attrs)?,
+        visibility_to_string(&ast.vis),
+        generic_type_name(&name_string, &type_params, "<", ">"),
+        rust_variants.join(",\n")
+    );
+    // As in `enum_to_string`, an enum whose every variant is skipped becomes the bottom type.
+    if ts_variants.is_empty() {
+        ts_variants.push("never".to_string());
+        flow_variants.push("empty".to_string());
+        elixir_variants.push("none()".to_string());
+    }
+    let ts_string = format!(
+        "type {} = {};",
+        decorated_name("typescript"),
+        ts_variants.join(" | ")
+    );
+    let flow_string = format!(
+        "type {} = {};",
+        decorated_name("flow"),
+        flow_variants.join(" | ")
+    );
+    let elixir_string = format!(
+        "defmodule {} do\n  @type t :: {}\nend",
+        container_attributes.decorated_name("elixir", &name_string),
+        elixir_variants.join(" | ")
+    );
+    // OCaml's variants are always tagged, so any JSON value is accepted.
+    let ocaml_string = format!(
+        "type {} = Yojson.Safe.t",
+        ocaml_type_name(&container_attributes.decorated_name("ocaml", &name_string))
+    );
+    // Likewise F#'s, so the variants are left as any value.
+    let fsharp_string = format!(
+        "type {} = obj",
+        container_attributes.decorated_name("fsharp", &name_string)
+    );
+    // A type alias can't be generic in Python, so the type parameters are left out.
+    let python_string = if python_variants.is_empty() {
+        format!(
+            "{} = NoReturn",
+            container_attributes.decorated_name("python", &name_string)
+        )
+    } else {
+        format!(
+            "{} = Union[{}]",
+            container_attributes.decorated_name("python", &name_string),
+            python_variants.join(", ")
+        )
+    };
+    let go_type_params: Vec<String> = type_params
+        .iter()
+        .map(|type_param| format!("{} any", type_param))
+        .collect();
+    let go_string = format!(
+        "type {} any",
+        generic_type_name(
+            &container_attributes.decorated_name("go", &name_string),
+            &go_type_params,
+            "[",
+            "]"
+        )
+    );
+    let java_string = format!(
+        "public class {} {{\n    public Object value;\n}}",
+        decorated_name("java")
+    );
+    let csharp_string = format!(
+        "public class {} {{\n    public object value;\n}}",
+        decorated_name("csharp")
+    );
+    let kotlin_string = format!("typealias {} = Any", decorated_name("kotlin"));
+    let swift_string = format!("typealias {} = Any", decorated_name("swift"));
+    let cpp_string = format!(
+        "using {} = std::any;",
+        container_attributes.decorated_name("cpp", &name_string)
+    );
+
+    let shared_impls = shared_impls(
+        name,
+        &ast.generics,
+        &referenced_types,
+        None,
+        quote!(),
+        schema_hash(&ast.data, container_attributes.rename_all)?,
+    );
+    let methods = [
+        ("typescript", quote!(to_typescript_string), ts_string),
+        ("python", quote!(to_python_string), python_string),
+        ("go", quote!(to_go_string), go_string),
+        ("java", quote!(to_java_string), java_string),
+        ("csharp", quote!(to_csharp_string), csharp_string),
+        ("kotlin", quote!(to_kotlin_string), kotlin_string),
+        ("swift", quote!(to_swift_string), swift_string),
+        ("cpp", quote!(to_cpp_string), cpp_string),
+        ("flow", quote!(to_flow_string), flow_string),
+        ("elixir", quote!(to_elixir_string), elixir_string),
+        ("ocaml", quote!(to_ocaml_string), ocaml_string),
+        ("fsharp", quote!(to_fsharp_string), fsharp_string),
+    ]
+    .into_iter()
+    .map(|(language, method, string)| {
+        language_methods(
+            language,
+            quote! {
+                pub fn #method() -> String {
+                    String::from(#string)
+                }
+            },
+        )
+    });
+    let rust_methods = language_methods(
+        "rust",
+        quote! {
+            pub fn to_rust_string() -> String {
+                Self::to_rust_string_with_derive(false)
+            }
+
+            /// Like `to_rust_string`, optionally annotating the enum with
+            /// `#[derive(StructToString)]` so the generated code is itself convertible.
+            pub fn to_rust_string_with_derive(derive: bool) -> String {
+                let mut res = String::new();
+                if derive {
+                    res.push_str("#[derive(StructToString)]\n");
+                }
+                res.push_str(#rust_string);
+                res
+            }
+        },
+    );
+
+    Ok(quote! {
+        #shared_impls
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #rust_methods
+            #(#methods)*
+        }
+    })
+}
+
+/// Generates the implementation for an enum. Enums whose variants carry no data become string
+/// enums (or the closest equivalent) in the other languages. Variants carrying data are only
+/// supported in `#[serde(untagged)]` enums, see `untagged_enum_to_string`.
+fn enum_to_string(
+    ast: &DeriveInput,
+    data_enum: &syn::DataEnum,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &ast.ident;
+    let name_string = name.to_string();
+    let container_attributes = ContainerAttributes::from_attrs(&ast.attrs)?;
+    if let Some(display) = &container_attributes.display {
+        return Err(syn::Error::new_spanned(
+            display,
+            "`display` is only supported on structs with named fields",
+        ));
+    }
+    let carries_data = data_enum
+        .variants
+        .iter()
+        .any(|variant| !matches!(variant.fields, syn::Fields::Unit));
+    if container_attributes.untagged && carries_data {
+        return untagged_enum_to_string(ast, data_enum, &container_attributes);
+    }
+    let ts_name = container_attributes.decorated_name("typescript", &name_string);
+    let python_name = container_attributes.decorated_name("python", &name_string);
+    let go_name = container_attributes.decorated_name("go", &name_string);
+    let java_name = container_attributes.decorated_name("java", &name_string);
+    let csharp_name = container_attributes.decorated_name("csharp", &name_string);
+    let kotlin_name = container_attributes.decorated_name("kotlin", &name_string);
+    let swift_name = container_attributes.decorated_name("swift", &name_string);
+    let cpp_name = container_attributes.decorated_name("cpp", &name_string);
+    let flow_name = container_attributes.decorated_name("flow", &name_string);
+    let elixir_name = container_attributes.decorated_name("elixir", &name_string);
+    let ocaml_name = ocaml_type_name(&container_attributes.decorated_name("ocaml", &name_string));
+    let fsharp_name = container_attributes.decorated_name("fsharp", &name_string);
+    let (impl_generics, ty_generics, where_clause) = ast.generics.split_for_impl();
+
+    let mut rust_variants = String::new();
+    let mut from_str_arms = String::new();
+    let mut ts_variants = Vec::new();
+    let mut elixir_variants = Vec::new();
+    let mut ocaml_variants = Vec::new();
+    let mut fsharp_variants = String::new();
+    let mut python_variants = String::new();
+    let mut go_variants = String::new();
+    let mut java_variants = Vec::new();
+    let mut csharp_variants = Vec::new();
+    let mut kotlin_variants = String::new();
+    let mut swift_variants = String::new();
+    let mut cpp_variants = Vec::new();
+    let mut proto_variants = Vec::new();
+    let mut next_value = Some(0);
+
+    for variant in &data_enum.variants {
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(syn::Error::new_spanned(
+                variant,
+                "StructToString only supports enums whose variants carry no data, unless the enum is `#[serde(untagged)]`",
+            ));
+        }
+        let variant_attributes = FieldAttributes::from_attrs(&variant.attrs)?;
+        let variant_name = variant.ident.to_string();
+
+        // Rust representation
+        match &variant.discriminant {
+            Some((_, discriminant)) => rust_variants.push_str(&format!(
+                "    {} = {},\n",
+                variant_name,
+                discriminant.to_token_stream()
+            )),
+            None => rust_variants.push_str(&format!("    {},\n", variant_name)),
+        }
+        from_str_arms.push_str(&format!(
+            "            \"{}\" => Ok(Self::{}),\n",
+            variant_name, variant_name
+        ));
+
+        // Discriminants follow Rust's numbering: explicit where given, otherwise one more
+        // than the previous variant. They're unknown from the first one that isn't a literal.
+        let value = match &variant.discriminant {
+            Some((_, discriminant)) => discriminant_value(discriminant),
+            None => next_value,
+        };
+        next_value = value.and_then(|value| value.checked_add(1));
+
+        // As with struct fields, serde's skip and rename only apply to the other languages.
+        if variant_attributes.skip {
+            continue;
+        }
+        let wire_name = match (&variant_attributes.rename, container_attributes.rename_all) {
+            (Some(rename), _) => rename.clone(),
+            (None, Some(rule)) => rule.apply_to_variant(&variant_name),
+            (None, None) => variant_name.clone(),
+        };
+        let screaming_name = screaming_snake_case(&variant_name);
+
+        ts_variants.push(format!("\"{}\"", wire_name));
+        elixir_variants.push(elixir_atom(&wire_name));
+        ocaml_variants.push(variant_name.clone());
+        fsharp_variants.push_str(&format!("\n    | {}", variant_name));
+        python_variants.push_str(&format!("    {} = \"{}\"\n", screaming_name, wire_name));
+        go_variants.push_str(&format!(
+            "    {}{} {} = \"{}\"\n",
+            go_name, variant_name, go_name, wire_name
+        ));
+        java_variants.push(format!("    {}", screaming_name));
+        csharp_variants.push(format!("    {}", variant_name));
+        cpp_variants.push(format!("    {}", variant_name));
+        kotlin_variants.push_str(&format!("    {},\n", screaming_name));
+        proto_variants.push((value, screaming_name.clone()));
+        swift_variants.push_str(&format!(
+            "    case {} = \"{}\"\n",
+            lower_camel_case(&variant_name),
+            wire_name
+        ));
+    }
+
+    let rust_string = format!(
+        "{}{}enum {} {{\n{}\n}}",
+        repr_attributes_to_string(&ast.attrs)?,
+        visibility_to_string(&ast.vis),
+        name_string,
+        rust_variants.trim_end_matches(",\n")
+    );
+    let from_str_string = format!(
+        "impl std::str::FromStr for {name} {{\n    type Err = String;\n\n    fn from_str(s: &str) -> Result<Self, Self::Err> {{\n        match s {{\n{arms}            _ => Err(format!(\"unknown {name}: {{}}\", s)),\n        }}\n    }}\n}}",
+        name = name_string,
+        arms = from_str_arms
+    );
+    // An enum with no variants left, because it has none or serde skips them all, has no
+    // values: it becomes the bottom type where there's one, and an empty type elsewhere.
+    let uninhabited = ts_variants.is_empty();
+    let (ts_union, flow_union, elixir_union) = if uninhabited {
+        (
+            "never".to_string(),
+            "empty".to_string(),
+            "none()".to_string(),
+        )
+    } else {
+        (
+            ts_variants.join(" | "),
+            ts_variants.join(" | "),
+            elixir_variants.join(" | "),
+        )
+    };
+    let ts_string = format!("type {} = {};", ts_name, ts_union);
+    let flow_string = format!("type {} = {};", flow_name, flow_union);
+    let elixir_string = format!(
+        "defmodule {} do\n  @type t :: {}\nend",
+        elixir_name, elixir_union
+    );
+    let ocaml_string = if uninhabited {
+        format!("type {} = |", ocaml_name)
+    } else {
+        format!("type {} = {}", ocaml_name, ocaml_variants.join(" | "))
+    };
+    let fsharp_string = if uninhabited {
+        format!("type {} = class end", fsharp_name)
+    } else {
+        format!("type {} ={}", fsharp_name, fsharp_variants)
+    };
+    if uninhabited {
+        python_variants.push_str("    pass\n");
+    }
+    let python_string = format!("class {}(str, Enum):\n{}", python_name, python_variants);
+    let go_string = if uninhabited {
+        format!("type {} string", go_name)
+    } else {
+        format!("type {} string\n\nconst (\n{})", go_name, go_variants)
+    };
+    let java_string = format!(
+        "public enum {} {{\n{}\n}}",
+        java_name,
+        java_variants.join(",\n")
+    );
+    let csharp_string = format!(
+        "public enum {} {{\n{}\n}}",
+        csharp_name,
+        csharp_variants.join(",\n")
+    );
+    let kotlin_string = format!("enum class {} {{\n{}}}", kotlin_name, kotlin_variants);
+    let cpp_string = format!(
+        "enum class {} {{\n{}\n}};",
+        cpp_name,
+        cpp_variants.join(",\n")
+    );
+    // proto3 enums are `int32`s, so only enums whose every value is known and fits in one get
+    // a proto output.
+    let proto_variants: Option<Vec<(i32, String)>> = proto_variants
+        .into_iter()
+        .map(|(value, variant)| Some((i32::try_from(value?).ok()?, variant)))
+        .collect();
+    let proto_method = match proto_variants {
+        Some(mut proto_variants) => {
+            // proto3 requires the first value to be zero, so the zero variant is moved to the
+            // front, or an unspecified placeholder added when there's none.
+            match proto_variants.iter().position(|(value, _)| *value == 0) {
+                Some(zero) => {
+                    let zero_variant = proto_variants.remove(zero);
+                    proto_variants.insert(0, zero_variant);
+                }
+                None => proto_variants.insert(
+                    0,
+                    (
+                        0,
+                        format!("{}_UNSPECIFIED", screaming_snake_case(&name_string)),
+                    ),
+                ),
+            }
+            let proto_string = format!(
+                "enum {} {{\n{}}}",
+                name_string,
+                proto_variants
+                    .iter()
+                    .map(|(value, variant)| format!("    {} = {};\n", variant, value))
+                    .collect::<String>()
+            );
+            quote! {
+                /// Returns the enum as a proto3 `enum`, numbered like the Rust discriminants.
+                pub fn to_proto_string() -> String {
+                    String::from(#proto_string)
+                }
+            }
+        }
+        None => quote!(),
+    };
+    // Swift rejects a raw type on an enum without cases.
+    let swift_string = if uninhabited {
+        format!("enum {} {{\n}}", swift_name)
+    } else {
+        format!("enum {}: String {{\n{}}}", swift_name, swift_variants)
+    };
+
+    // An enum field is written as its first variant in a TOML example.
+    let toml_methods = match ts_variants.first() {
+        Some(toml_value) => quote! {
+            fn toml_value() -> Option<String> {
+                Some(String::from(#toml_value))
+            }
+        },
+        None => quote!(),
+    };
+    let shared_impls = shared_impls(
+        name,
+        &ast.generics,
+        &[],
+        None,
+        toml_methods,
+        schema_hash(&ast.data, container_attributes.rename_all)?,
+    );
+
+    let rust_methods = language_methods(
+        "rust",
+        quote! {
+            pub fn to_rust_string() -> String {
+                Self::to_rust_string_with_derive(false)
+            }
+
+            /// Like `to_rust_string`, optionally annotating the enum with
+            /// `#[derive(StructToString)]` so the generated code is itself convertible.
+            pub fn to_rust_string_with_derive(derive: bool) -> String {
+                let mut res = String::new();
+                if derive {
+                    res.push_str("#[derive(StructToString)]\n");
+                }
+                res.push_str(#rust_string);
+                res
+            }
+
+            /// Returns an `impl FromStr` skeleton parsing the Rust variant names, with an error
+            /// for any other string.
+            pub fn to_rust_from_str_string() -> String {
+                String::from(#from_str_string)
+            }
+        },
+    );
+
+    let typescript_methods = language_methods(
+        "typescript",
+        quote! {
+            pub fn to_typescript_string() -> String {
+                String::from(#ts_string)
+            }
+        },
+    );
+
+    let python_methods = language_methods(
+        "python",
+        quote! {
+            pub fn to_python_string() -> String {
+                String::from(#python_string)
+            }
+        },
+    );
+
+    let go_methods = language_methods(
+        "go",
+        quote! {
+            pub fn to_go_string() -> String {
+                String::from(#go_string)
+            }
+        },
+    );
+
+    let java_methods = language_methods(
+        "java",
+        quote! {
+            pub fn to_java_string() -> String {
+                String::from(#java_string)
+            }
+        },
+    );
+
+    let csharp_methods = language_methods(
+        "csharp",
+        quote! {
+            pub fn to_csharp_string() -> String {
+                String::from(#csharp_string)
+            }
+        },
+    );
+
+    let kotlin_methods = language_methods(
+        "kotlin",
+        quote! {
+            pub fn to_kotlin_string() -> String {
+                String::from(#kotlin_string)
+            }
+        },
+    );
+
+    let swift_methods = language_methods(
+        "swift",
+        quote! {
+            pub fn to_swift_string() -> String {
+                String::from(#swift_string)
+            }
+        },
+    );
+
+    let cpp_methods = language_methods(
+        "cpp",
+        quote! {
+            pub fn to_cpp_string() -> String {
+                String::from(#cpp_string)
+            }
+        },
+    );
+
+    let flow_methods = language_methods(
+        "flow",
+        quote! {
+            pub fn to_flow_string() -> String {
+                String::from(#flow_string)
+            }
+        },
+    );
+
+    let elixir_methods = language_methods(
+        "elixir",
+        quote! {
+            pub fn to_elixir_string() -> String {
+                String::from(#elixir_string)
+            }
+        },
+    );
+
+    let ocaml_methods = language_methods(
+        "ocaml",
+        quote! {
+            pub fn to_ocaml_string() -> String {
+                String::from(#ocaml_string)
+            }
+        },
+    );
+
+    let fsharp_methods = language_methods(
+        "fsharp",
+        quote! {
+            pub fn to_fsharp_string() -> String {
+                String::from(#fsharp_string)
+            }
+        },
+    );
+
+    Ok(quote! {
+        #shared_impls
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            #rust_methods
+            #typescript_methods
+            #python_methods
+            #go_methods
+            #java_methods
+            #csharp_methods
+            #kotlin_methods
+            #swift_methods
+            #cpp_methods
+            #flow_methods
+            #elixir_methods
+            #ocaml_methods
+            #fsharp_methods
+            #proto_method
+        }
+    })
+}
+
+/// Turns a `#[struct_to_string(display = "...")]` template such as `"{id}-{name:>8}"` into the
+/// format string of a `write!` call and the fields it writes, in order. As in `format!`, `{{`
+/// and `}}` are literal braces.
+fn display_template(
+    template: &syn::LitStr,
+    fields: &[String],
+) -> syn::Result<(String, Vec<String>)> {
+    let value = template.value();
+    let mut format = String::new();
+    let mut arguments = Vec::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' | '}' if chars.peek() == Some(&c) => {
+                chars.next();
+                format.push(c);
+                format.push(c);
+            }
+            '{' => {
+                let mut placeholder = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => placeholder.push(c),
+                        None => {
+                            return Err(syn::Error::new_spanned(
+                                template,
+                                "unclosed `{` in the display template",
+                            ))
+                        }
+                    }
+                }
+                let (field, spec) = match placeholder.split_once(':') {
+                    Some((field, spec)) => (field.trim(), Some(spec)),
+                    None => (placeholder.trim(), None),
+                };
+                if !fields.iter().any(|name| name == field) {
+                    return Err(syn::Error::new_spanned(
+                        template,
+                        format!(
+                            "the display template names `{}`, which isn't a field",
+                            field
+                        ),
+                    ));
+                }
+                format.push('{');
+                if let Some(spec) = spec {
+                    format.push(':');
+                    format.push_str(spec);
+                }
+                format.push('}');
+                arguments.push(field.to_string());
+            }
+            '}' => {
+                return Err(syn::Error::new_spanned(
+                    template,
+                    "unmatched `}` in the display template, write `}}` for a literal one",
+                ))
+            }
+            _ => format.push(c),
+        }
+    }
+    Ok((format, arguments))
+}
+
+/// Hashes the serialized names and the types of a type's fields, and the serialized names of
+/// its variants, with 64-bit FNV-1a, leaving out what serde skips. Unlike `std`'s hashers, it
+/// gives the same hash on every Rust version and platform.
+fn schema_hash(data: &syn::Data, rename_all: Option<RenameRule>) -> syn::Result<u64> {
+    fn push_fields(
+        shape: &mut String,
+        fields: &syn::Fields,
+        rename_all: Option<RenameRule>,
+    ) -> syn::Result<()> {
+        for (index, field) in fields.iter().enumerate() {
+            let field_attributes = FieldAttributes::from_attrs(&field.attrs)?;
+            if field_attributes.skip {
+                continue;
+            }
+            let field_name = match (&field_attributes.rename, &field.ident, rename_all) {
+                (Some(rename), _, _) => rename.clone(),
+                (None, Some(ident), Some(rule)) => rule.apply_to_field(&ident.to_string()),
+                (None, Some(ident), None) => ident.to_string(),
+                (None, None, _) => index.to_string(),
+            };
+            shape.push_str(&field_name);
+            shape.push(':');
+            shape.push_str(&rust_type_string(&field.ty));
+            shape.push(';');
+        }
+        Ok(())
+    }
+
+    let mut shape = String::new();
+    match data {
+        syn::Data::Struct(data_struct) => push_fields(&mut shape, &data_struct.fields, rename_all)?,
+        syn::Data::Enum(data_enum) => {
+            for variant in &data_enum.variants {
+                let variant_attributes = FieldAttributes::from_attrs(&variant.attrs)?;
+                if variant_attributes.skip {
+                    continue;
+                }
+                let variant_name = variant.ident.to_string();
+                shape.push_str(&match (&variant_attributes.rename, rename_all) {
+                    (Some(rename), _) => rename.clone(),
+                    (None, Some(rule)) => rule.apply_to_variant(&variant_name),
+                    (None, None) => variant_name,
+                });
+                shape.push('{');
+                // An enum's `rename_all` renames its variants, not their fields.
+                push_fields(&mut shape, &variant.fields, None)?;
+                shape.push('}');
+            }
+        }
+        // Rejected before getting here.
+        syn::Data::Union(_) => {}
+    }
+    Ok(shape.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    }))
+}
+
+/// Evaluates an enum discriminant when it's an integer literal, possibly negated, that fits in
+/// an `i64`. Other expressions, such as `1 << 2` or a constant, can't be evaluated by a macro.
+fn discriminant_value(discriminant: &syn::Expr) -> Option<i64> {
+    match discriminant {
+        syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Int(lit_int),
+            ..
+        }) => lit_int.base10_parse().ok(),
+        syn::Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => discriminant_value(expr).and_then(i64::checked_neg),
+        _ => None,
+    }
+}
+
+/// Converts a `PascalCase` name to `SCREAMING_SNAKE_CASE`, e.g. `InProgress` to `IN_PROGRESS`.
+fn screaming_snake_case(name: &str) -> String {
+    let chars: Vec<char> = name.chars().collect();
+    let mut res = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if c.is_uppercase() && i > 0 {
+            let previous = chars[i - 1];
+            let next_is_lowercase = chars.get(i + 1).is_some_and(|next| next.is_lowercase());
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next_is_lowercase)
+            {
+                res.push('_');
+            }
+        }
+        res.extend(c.to_uppercase());
+    }
+    res
+}
+
+/// Converts a `snake_case` name to `PascalCase`, e.g. `user_name` to `UserName`, which is how Go
+/// exports a field.
+fn pascal_case(name: &str) -> String {
+    name.trim_start_matches("r#")
+        .split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+/// Converts a field name to an exported Go name, writing the initialisms Go style guides keep
+/// in one case in upper case, e.g. `avatar_url` to `AvatarURL`.
+fn go_field_name(name: &str) -> String {
+    const INITIALISMS: &[&str] = &[
+        "ACL", "API", "ASCII", "CPU", "CSS", "DNS", "EOF", "GUID", "HTML", "HTTP", "HTTPS", "ID",
+        "IP", "JSON", "QPS", "RAM", "RPC", "SLA", "SMTP", "SQL", "SSH", "TCP", "TLS", "TTL", "UDP",
+        "UI", "UID", "UUID", "URI", "URL", "UTF8", "VM", "XML", "XMPP", "XSRF", "XSS",
+    ];
+    name.trim_start_matches("r#")
+        .split('_')
+        .map(|word| {
+            let upper = word.to_uppercase();
+            if INITIALISMS.contains(&upper.as_str()) {
+                upper
+            } else {
+                pascal_case(word)
+            }
+        })
+        .collect()
+}
+
+/// Converts a `PascalCase` name to `lowerCamelCase`, e.g. `InProgress` to `inProgress`.
+fn lower_camel_case(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Generates what every derived type gets regardless of its shape: the `StructToString`
+/// trait, the recursive collection of referenced definitions, `to_string_in`,
+/// `referenced_types` and the `*_recursive` methods. The `to_*_string` methods themselves
+/// are left to the caller.
+fn shared_impls(
+    name: &Ident,
+    generics: &syn::Generics,
+    referenced_types: &[(String, Type)],
+    struct_parts: Option<StructParts>,
+    toml_methods: proc_macro2::TokenStream,
+    schema_hash: u64,
+) -> proc_macro2::TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut referenced_type_names: Vec<&String> = Vec::new();
+    for (name, _) in referenced_types {
+        if !referenced_type_names.contains(&name) {
+            referenced_type_names.push(name);
+        }
+    }
+    let referenced_types: Vec<&Type> = referenced_types.iter().map(|(_, ty)| ty).collect();
+
+    let language_arms: Vec<_> = enabled_languages()
+        .map(|(language, method)| {
+            let language = Ident::new(language, proc_macro2::Span::call_site());
+            let method = Ident::new(method, proc_macro2::Span::call_site());
+            quote!(::struct_to_string::Language::#language => Self::#method())
+        })
+        .collect();
+    // Every language is reindented from the indentation it was written with, while only the
+    // field lists of structs are renamed.
+    let options_arms: Vec<_> = LANGUAGE_KEYS
+        .iter()
+        .zip(LANGUAGES)
+        .filter(|(key, _)| language_enabled(key))
+        .map(|(key, (language, method))| {
+            let language = Ident::new(language, proc_macro2::Span::call_site());
+            let method = Ident::new(method, proc_macro2::Span::call_site());
+            let output = struct_parts
+                .as_ref()
+                .and_then(|parts| parts.options_arms.iter().find(|(arm, _)| arm == key))
+                .map_or_else(|| quote!(Self::#method()), |(_, output)| output.clone());
+            let unit = struct_parts
+                .as_ref()
+                .and_then(|parts| parts.indent)
+                .unwrap_or_else(|| indent_unit(key));
+            quote! {
+                ::struct_to_string::Language::#language => {
+                    ::struct_to_string::__private::reindent(#output, #unit, options)
+                }
+            }
+        })
+        .collect();
+    let trait_methods = enabled_languages().map(|(_, method)| {
+        let method = Ident::new(method, proc_macro2::Span::call_site());
+        quote! {
+            fn #method() -> String {
+                <#name #ty_generics>::#method()
+            }
+        }
+    });
+    let all_strings_entries = LANGUAGE_KEYS
+        .iter()
+        .zip(LANGUAGES)
+        .filter(|(key, _)| language_enabled(key))
+        .map(|(key, (_, method))| {
+            let method = Ident::new(method, proc_macro2::Span::call_site());
+            quote!((#key, Self::#method()))
+        });
+    let recursive_methods = enabled_languages().map(|(language, method)| {
+        let language = Ident::new(language, proc_macro2::Span::call_site());
+        let recursive_method = Ident::new(&format!("{}_recursive", method), proc_macro2::Span::call_site());
+        let doc = format!(
+            "Like `{}`, followed by the definitions of every referenced type that also derives `StructToString`.",
+            method
+        );
+        quote! {
+            #[doc = #doc]
+            pub fn #recursive_method() -> String {
+                ::struct_to_string::__private::recursive_definitions::<Self>(::struct_to_string::Language::#language)
+            }
+        }
+    });
+
+    // Only structs have fields to inline into a type flattening them.
+    let flattened_fields_method = match &struct_parts {
+        Some(StructParts {
+            flattened_fields_arms,
+            ..
+        }) => quote! {
+            fn flattened_fields(
+                kind: &str,
+                options: &::struct_to_string::__private::Options,
+            ) -> Option<String> {
+                match kind {
+                    #(#flattened_fields_arms,)*
+                    _ => None,
+                }
+            }
+        },
+        None => quote!(),
+    };
+    let field_names = struct_parts.map_or(&[][..], |parts| parts.field_names);
+
+    let cpp_guard_method = language_methods(
+        "cpp",
+        quote! {
+            /// Like `to_cpp_string`, wrapped in an `#ifndef` include guard with the given name.
+            pub fn to_cpp_string_with_guard(guard: &str) -> String {
+                let mut res = String::from("#ifndef ");
+                res.push_str(guard);
+                res.push_str("\n#define ");
+                res.push_str(guard);
+                res.push_str("\n\n");
+                res.push_str(&Self::to_cpp_string());
+                res.push_str("\n\n#endif");
+                res
+            }
+        },
+    );
+
+    quote! {
+        impl #impl_generics ::struct_to_string::StructToString for #name #ty_generics #where_clause {
+            #(#trait_methods)*
+
+            fn field_names() -> Vec<&'static str> {
+                vec![#(#field_names),*]
+            }
+
+            fn schema_hash() -> u64 {
+                #schema_hash
+            }
+
+            fn to_string_with(
+                language: ::struct_to_string::Language,
+                options: &::struct_to_string::__private::Options,
+            ) -> String {
+                match language {
+                    #(#options_arms)*
+                }
+            }
+        }
+
+        impl #impl_generics ::struct_to_string::__private::CollectDefinitions for #name #ty_generics #where_clause {
+            fn collect_definitions(
+                language: ::struct_to_string::Language,
+                seen: &mut Vec<&'static str>,
+                out: &mut Vec<String>,
+            ) {
+                // Keyed on the full path and type arguments, so that `a::User` and `b::User`, or
+                // `Page<A>` and `Page<B>`, are each visited.
+                let type_name = ::core::any::type_name::<Self>();
+                if seen.contains(&type_name) {
+                    return;
+                }
+                seen.push(type_name);
+
+                {
+                    use ::struct_to_string::__private::{ViaDerive as _, ViaFallback as _};
+                    #((&::core::marker::PhantomData::<#referenced_types>).collect_definitions(language, seen, out);)*
+                }
+
+                // The instances of a generic type share its definition, which is written once.
+                let definition = match language {
+                    #(#language_arms,)*
+                };
+                if !out.contains(&definition) {
+                    out.push(definition);
+                }
+            }
+
+            #flattened_fields_method
+            #toml_methods
+        }
+
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Returns the definition of this type in the given language.
+            pub fn to_string_in(language: ::struct_to_string::Language) -> String {
+                match language {
+                    #(#language_arms,)*
+                }
+            }
+
+            /// Returns the definition of this type in every language, keyed by the lowercase
+            /// language name, e.g. `"rust"` or `"typescript"`, and sorted by it.
+            pub fn to_all_strings() -> ::std::collections::BTreeMap<&'static str, String> {
+                ::std::collections::BTreeMap::from([#(#all_strings_entries),*])
+            }
+
+            #cpp_guard_method
+
+            /// Starts a builder composing the output options in one place.
+            pub fn string_builder() -> ::struct_to_string::StructToStringBuilder<Self> {
+                ::struct_to_string::StructToStringBuilder::new()
+            }
+
+            /// The names of the custom types referenced by this type's fields.
+            pub fn referenced_types() -> Vec<&'static str> {
+                vec![#(#referenced_type_names),*]
+            }
+
+            #(#recursive_methods)*
+        }
+    }
+}
+
+/// The parts of the shared implementations that only structs have.
+struct StructParts<'a> {
+    flattened_fields_arms: &'a [proc_macro2::TokenStream],
+    field_names: &'a [String],
+    /// The output of each language with the builder's `options`, keyed like `LANGUAGE_KEYS`.
+    /// Languages without one are written by their plain method.
+    options_arms: &'a [(&'static str, proc_macro2::TokenStream)],
+    /// The `indent` type attribute, which the fields are written with instead of the
+    /// language's own indentation.
+    indent: Option<&'a str>,
+}
+
+/// The `Language` variants, along with the method producing each one.
+const LANGUAGES: &[(&str, &str)] = &[
+    ("Rust", "to_rust_string"),
+    ("TypeScript", "to_typescript_string"),
+    ("Python", "to_python_string"),
+    ("Go", "to_go_string"),
+    ("Java", "to_java_string"),
+    ("CSharp", "to_csharp_string"),
+    ("Kotlin", "to_kotlin_string"),
+    ("Swift", "to_swift_string"),
+    ("Cpp", "to_cpp_string"),
+    ("Flow", "to_flow_string"),
+    ("Elixir", "to_elixir_string"),
+    ("OCaml", "to_ocaml_string"),
+    ("FSharp", "to_fsharp_string"),
+];
+
+/// The language keys accepted by the `#[struct_to_string(<language> = "...")]` type override,
+/// which also key `to_all_strings`. Listed in the same order as `LANGUAGES`.
+const LANGUAGE_KEYS: &[&str] = &[
+    "rust",
+    "typescript",
+    "python",
+    "go",
+    "java",
+    "csharp",
+    "kotlin",
+    "swift",
+    "cpp",
+    "flow",
+    "elixir",
+    "ocaml",
+    "fsharp",
+];
+
+/// Whether the Cargo feature for the language with the given key is enabled. The methods of
+/// disabled languages aren't generated at all, which keeps expansion cheap for crates that only
+/// need a few of them.
+// Which arms are `true` depends on the enabled features, so this can't always be a `matches!`.
+#[allow(clippy::match_like_matches_macro)]
+fn language_enabled(language: &str) -> bool {
+    match language {
+        "rust" => cfg!(feature = "rust"),
+        "typescript" => cfg!(feature = "typescript"),
+        "python" => cfg!(feature = "python"),
+        "go" => cfg!(feature = "go"),
+        "java" => cfg!(feature = "java"),
+        "csharp" => cfg!(feature = "csharp"),
+        "kotlin" => cfg!(feature = "kotlin"),
+        "swift" => cfg!(feature = "swift"),
+        "cpp" => cfg!(feature = "cpp"),
+        "flow" => cfg!(feature = "flow"),
+        "elixir" => cfg!(feature = "elixir"),
+        "ocaml" => cfg!(feature = "ocaml"),
+        "fsharp" => cfg!(feature = "fsharp"),
+        _ => false,
+    }
+}
+
+/// The entries of `LANGUAGES` whose Cargo feature is enabled.
+fn enabled_languages() -> impl Iterator<Item = &'static (&'static str, &'static str)> {
+    LANGUAGE_KEYS
+        .iter()
+        .zip(LANGUAGES)
+        .filter(|(key, _)| language_enabled(key))
+        .map(|(_, language)| language)
+}
+
+/// Returns the methods for the language with the given key, or nothing when its Cargo feature
+/// is disabled.
+fn language_methods(language: &str, methods: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+    if language_enabled(language) {
+        methods
+    } else {
+        proc_macro2::TokenStream::new()
+    }
+}
+
+/// The field lines of one output, such as the TypeScript interface body. They're written out
+/// when the output is generated: the field names follow the builder's `rename_all`, and the
+/// fields of a `#[serde(flatten)]` field's type are only known to that type's own derive.
+struct FieldList {
+    /// Identifies the output, for looking up the matching lines of a flattened type.
+    kind: &'static str,
+    chunks: Vec<FieldChunk>,
+}
+
+enum FieldChunk {
+    Lines(Vec<Segment>),
+    /// A flattened field, along with the lines to use when its type doesn't derive
+    /// `StructToString`.
+    Flattened {
+        ty: Box<Type>,
+        fallback: Vec<Segment>,
+    },
+}
+
+enum Segment {
+    Text(String),
+    /// A field's serialized name.
+    Name(String),
+    /// A `&str` expression, for text depending on the builder's options.
+    Tokens(proc_macro2::TokenStream),
+}
+
+impl FieldList {
+    fn new(kind: &'static str) -> Self {
+        FieldList {
+            kind,
+            chunks: Vec::new(),
+        }
+    }
+
+    /// Starts the lines of the next field, which is flattened when given its type.
+    fn start_field(&mut self, flattened: Option<&Type>) {
+        match flattened {
+            Some(ty) => self.chunks.push(FieldChunk::Flattened {
+                ty: Box::new(ty.clone()),
+                fallback: Vec::new(),
+            }),
+            None => {
+                if !matches!(self.chunks.last(), Some(FieldChunk::Lines(_))) {
+                    self.chunks.push(FieldChunk::Lines(Vec::new()));
+                }
+            }
+        }
+    }
+
+    fn segments(&mut self) -> &mut Vec<Segment> {
+        if self.chunks.is_empty() {
+            self.chunks.push(FieldChunk::Lines(Vec::new()));
+        }
+        match self.chunks.last_mut().unwrap() {
+            FieldChunk::Lines(segments) => segments,
+            FieldChunk::Flattened { fallback, .. } => fallback,
+        }
+    }
+
+    fn push_str(&mut self, string: &str) {
+        let segments = self.segments();
+        match segments.last_mut() {
+            Some(Segment::Text(text)) => text.push_str(string),
+            _ => segments.push(Segment::Text(string.to_string())),
+        }
+    }
+
+    /// Pushes a field's serialized name between `before` and `after`.
+    fn push_field(&mut self, before: &str, name: &str, after: &str) {
+        self.push_str(before);
+        self.segments().push(Segment::Name(name.to_string()));
+        self.push_str(after);
+    }
+
+    /// Pushes the text of the `choices` arm that `scrutinee` matches, or the text itself when
+    /// every arm has the same.
+    fn push_choice(
+        &mut self,
+        scrutinee: proc_macro2::TokenStream,
+        choices: Vec<(proc_macro2::TokenStream, String)>,
+    ) {
+        if choices.iter().all(|(_, text)| *text == choices[0].1) {
+            self.push_str(&choices[0].1);
+            return;
+        }
+        let (patterns, texts): (Vec<_>, Vec<_>) = choices.into_iter().unzip();
+        self.push_tokens(quote! {
+            match #scrutinee {
+                #(#patterns => #texts,)*
+            }
+        });
+    }
+
+    fn push_tokens(&mut self, tokens: proc_macro2::TokenStream) {
+        self.segments().push(Segment::Tokens(tokens));
+    }
+
+    /// Replaces each level of indentation the lines were written with by `indent`.
+    fn reindent(&mut self, indent: &str) {
+        let unit = indent_unit(self.kind);
+        for chunk in &mut self.chunks {
+            let (FieldChunk::Lines(segments)
+            | FieldChunk::Flattened {
+                fallback: segments, ..
+            }) = chunk;
+            // Only the text following a newline, or starting the field, starts a line.
+            let mut line_start = true;
+            for segment in segments {
+                match segment {
+                    Segment::Text(text) => {
+                        let head_len = match line_start {
+                            true => 0,
+                            false => text.find('\n').map_or(text.len(), |i| i + 1),
+                        };
+                        let (head, tail) = text.split_at(head_len);
+                        *text = format!("{}{}", head, reindent(tail, unit, indent));
+                        line_start = text.ends_with('\n');
+                    }
+                    Segment::Name(_) | Segment::Tokens(_) => line_start = false,
+                }
+            }
+        }
+    }
+}
+
+/// The indentation each language, or output kind, is written with: two spaces in Zod, Flow,
+/// Elixir and OCaml, and four in every other.
+fn indent_unit(kind: &str) -> &'static str {
+    match kind {
+        "zod" | "flow" | "elixir" | "ocaml" => "  ",
+        _ => "    ",
+    }
+}
+
+/// Writes an `Option<&str>` as the expression creating it.
+fn option_tokens(value: Option<&str>) -> proc_macro2::TokenStream {
+    match value {
+        Some(value) => quote!(Some(#value)),
+        None => quote!(None),
+    }
+}
+
+/// Replaces each leading `unit` of every line with `indent`.
+fn reindent(lines: &str, unit: &str, indent: &str) -> String {
+    let mut res = String::new();
+    for line in lines.split_inclusive('\n') {
+        let mut rest = line;
+        while let Some(stripped) = rest.strip_prefix(unit) {
+            res.push_str(indent);
+            rest = stripped;
+        }
+        res.push_str(rest);
+    }
+    res
+}
+
+impl quote::ToTokens for Segment {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(match self {
+            Segment::Text(text) => quote!(::struct_to_string::__private::Piece::Text(#text)),
+            Segment::Name(name) => quote!(::struct_to_string::__private::Piece::Name(#name)),
+            Segment::Tokens(text) => quote!(::struct_to_string::__private::Piece::Text(#text)),
+        });
+    }
+}
+
+/// Expands to the written out lines, with the `options` of the generated method using them.
+impl quote::ToTokens for FieldList {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let kind = self.kind;
+        let pieces = self.chunks.iter().map(|chunk| match chunk {
+            FieldChunk::Lines(segments) => quote!(#(#segments,)*),
+            FieldChunk::Flattened { ty, fallback } => quote! {
+                ::struct_to_string::__private::Piece::Flattened(
+                    (&::core::marker::PhantomData::<#ty>).flattened_fields(#kind, options),
+                    &[#(#fallback),*],
+                ),
+            },
+        });
+        let render = quote!(::struct_to_string::__private::render(options, &[#(#pieces)*]));
+
+        if self
+            .chunks
+            .iter()
+            .all(|chunk| matches!(chunk, FieldChunk::Lines(_)))
+        {
+            render.to_tokens(tokens);
+            return;
+        }
+        tokens.extend(quote! {
+            {
+                use ::struct_to_string::__private::{ViaDerive as _, ViaFallback as _};
+                #render
+            }
+        });
+    }
+}
+
+/// Options parsed from the `#[struct_to_string(...)]` and `#[serde(...)]` attributes on the
+/// type itself.
+#[derive(Default)]
+struct ContainerAttributes {
+    name_prefixes: Vec<(String, String)>,
+    name_suffixes: Vec<(String, String)>,
+    untagged: bool,
+    rename_all: Option<RenameRule>,
+    pub_only: bool,
+    indent: Option<String>,
+    typescript_option: Option<String>,
+    display: Option<syn::LitStr>,
+}
+
+impl ContainerAttributes {
+    fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut container_attributes = ContainerAttributes::default();
+
+        for attr in attrs {
+            if attr.path().is_ident("serde") {
+                attr.parse_nested_meta(|meta| {
+                    if meta.path.is_ident("untagged") {
+                        container_attributes.untagged = true;
+                        Ok(())
+                    } else if meta.path.is_ident("rename_all") {
+                        let rule = if meta.input.peek(syn::Token![=]) {
+                            Some(meta.value()?.parse::<syn::LitStr>()?)
+                        } else {
+                            // `rename_all(serialize = "...", deserialize = "...")`
+                            let mut serialize = None;
+                            meta.parse_nested_meta(|nested| {
+                                if nested.path.is_ident("serialize") {
+                                    serialize = Some(nested.value()?.parse::<syn::LitStr>()?);
+                                    Ok(())
+                                } else {
+                                    skip_meta_value(&nested)
+                                }
+                            })?;
+                            serialize
+                        };
+                        if let Some(rule) = rule {
+                            container_attributes.rename_all = Some(RenameRule::parse(&rule)?);
+                        }
+                        Ok(())
+                    } else {
+                        skip_meta_value(&meta)
+                    }
+                })?;
+                continue;
+            }
+
+            if !attr.path().is_ident("struct_to_string") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("pub_only") {
+                    container_attributes.pub_only = true;
+                    return Ok(());
+                }
+                if meta.path.is_ident("indent") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    container_attributes.indent = Some(value.value());
+                    return Ok(());
+                }
+                if meta.path.is_ident("typescript_option") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    container_attributes.typescript_option = Some(value.value());
+                    return Ok(());
+                }
+                if meta.path.is_ident("display") {
+                    container_attributes.display = Some(meta.value()?.parse()?);
+                    return Ok(());
+                }
+                // The Rust output reproduces the source definition, so its name is left alone.
+                for language in LANGUAGE_KEYS.iter().filter(|language| **language != "rust") {
+                    let affixes = if meta.path.is_ident(&format!("{}_prefix", language)) {
+                        &mut container_attributes.name_prefixes
+                    } else if meta.path.is_ident(&format!("{}_suffix", language)) {
+                        &mut container_attributes.name_suffixes
+                    } else {
+                        continue;
+                    };
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    affixes.push((language.to_string(), value.value()));
+                    return Ok(());
+                }
+                Err(meta.error("unsupported struct_to_string attribute"))
+            })?;
+        }
+
+        Ok(container_attributes)
+    }
+
+    /// Returns the type name as written in the given language, with any prefix and suffix.
+    fn decorated_name(&self, language: &str, name: &str) -> String {
+        let affix = |affixes: &[(String, String)]| {
+            affixes
+                .iter()
+                .rev()
+                .find(|(key, _)| key == language)
+                .map(|(_, affix)| affix.clone())
+                .unwrap_or_default()
+        };
+        format!(
+            "{}{}{}",
+            affix(&self.name_prefixes),
+            name,
+            affix(&self.name_suffixes)
+        )
+    }
+}
+
+/// A serde `rename_all` rule, renaming the fields of a struct or the variants of an enum.
+#[derive(Clone, Copy)]
+enum RenameRule {
+    Lower,
+    Upper,
+    Pascal,
+    Camel,
+    Snake,
+    ScreamingSnake,
+    Kebab,
+    ScreamingKebab,
+}
+
+impl RenameRule {
+    fn parse(rule: &syn::LitStr) -> syn::Result<Self> {
+        Ok(match rule.value().as_str() {
+            "lowercase" => RenameRule::Lower,
+            "UPPERCASE" => RenameRule::Upper,
+            "PascalCase" => RenameRule::Pascal,
+            "camelCase" => RenameRule::Camel,
+            "snake_case" => RenameRule::Snake,
+            "SCREAMING_SNAKE_CASE" => RenameRule::ScreamingSnake,
+            "kebab-case" => RenameRule::Kebab,
+            "SCREAMING-KEBAB-CASE" => RenameRule::ScreamingKebab,
+            other => {
+                return Err(syn::Error::new_spanned(
+                    rule,
+                    format!("unknown serde rename_all rule `{}`", other),
+                ))
+            }
+        })
+    }
+
+    /// Renames a `snake_case` field the way serde does.
+    fn apply_to_field(self, field: &str) -> String {
+        match self {
+            RenameRule::Lower | RenameRule::Snake => field.to_string(),
+            RenameRule::Upper | RenameRule::ScreamingSnake => field.to_uppercase(),
+            RenameRule::Pascal => pascal_case(field),
+            RenameRule::Camel => lower_camel_case(&pascal_case(field)),
+            RenameRule::Kebab => field.replace('_', "-"),
+            RenameRule::ScreamingKebab => field.replace('_', "-").to_uppercase(),
+        }
+    }
+
+    /// Renames a `PascalCase` variant the way serde does, which splits before every capital.
+    fn apply_to_variant(self, variant: &str) -> String {
+        let snake = || {
+            let mut res = String::new();
+            for (i, c) in variant.chars().enumerate() {
+                if c.is_uppercase() && i > 0 {
+                    res.push('_');
+                }
+                res.extend(c.to_lowercase());
+            }
+            res
+        };
+        match self {
+            RenameRule::Lower => variant.to_lowercase(),
+            RenameRule::Upper => variant.to_uppercase(),
+            RenameRule::Pascal => variant.to_string(),
+            RenameRule::Camel => lower_camel_case(variant),
+            RenameRule::Snake => snake(),
+            RenameRule::ScreamingSnake => snake().to_uppercase(),
+            RenameRule::Kebab => snake().replace('_', "-"),
+            RenameRule::ScreamingKebab => snake().replace('_', "-").to_uppercase(),
+        }
+    }
+}
+
+/// Options parsed from the `#[struct_to_string(...)]` and `#[serde(...)]` attributes on a field.
+#[derive(Default)]
+struct FieldAttributes {
+    go_validate: Option<String>,
+    map_keys: Option<Vec<String>>,
+    type_overrides: Vec<(String, String)>,
+    as_type: Option<String>,
+    serde_bytes: bool,
+    flatten: bool,
+    pattern: Option<String>,
+    format: Option<String>,
+    skip: bool,
+    rename: Option<String>,
+}
+
+impl FieldAttributes {
+    fn from_attrs(attrs: &[syn::Attribute]) -> syn::Result<Self> {
+        let mut field_attributes = FieldAttributes::default();
+
+        for attr in attrs {
+            if attr.path().is_ident("serde") {
+                field_attributes.parse_serde_attr(attr)?;
+                continue;
+            }
+
+            if !attr.path().is_ident("struct_to_string") {
+                continue;
+            }
+
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("as") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    field_attributes.as_type = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("go_validate") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    field_attributes.go_validate = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("pattern") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    field_attributes.pattern = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("format") {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    field_attributes.format = Some(value.value());
+                    Ok(())
+                } else if meta.path.is_ident("map_keys") {
+                    let content;
+                    syn::parenthesized!(content in meta.input);
+                    let keys = content
+                        .parse_terminated(|input| input.parse::<syn::LitStr>(), Token![,])?;
+                    field_attributes.map_keys = Some(keys.iter().map(syn::LitStr::value).collect());
+                    Ok(())
+                } else if let Some(language) = LANGUAGE_KEYS
+                    .iter()
+                    .find(|language| meta.path.is_ident(language))
+                    // `ts` is accepted as a shorthand for `typescript`.
+                    .or_else(|| meta.path.is_ident("ts").then_some(&"typescript"))
+                {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    field_attributes
+                        .type_overrides
+                        .push((language.to_string(), value.value()));
+                    Ok(())
+                } else {
+                    Err(meta.error(format!(
+                        "unsupported struct_to_string field attribute `{}`, expected `as`, `format`, `go_validate`, `map_keys`, `pattern` or a language override: `{}` or `ts`",
+                        meta.path.to_token_stream().to_string().replace(' ', ""),
+                        LANGUAGE_KEYS.join("`, `")
+                    )))
+                }
+            })?;
+        }
+
+        Ok(field_attributes)
+    }
+
+    /// Returns the type explicitly requested for this field in the given language, if any.
+    /// A per-language override wins over `as`, which covers every language but Rust.
+    fn type_override(&self, language: &str) -> Option<&str> {
+        self.type_overrides
+            .iter()
+            .find(|(key, _)| key == language)
+            .map(|(_, value)| value.as_str())
+            .or(match language {
+                "rust" => None,
+                _ => self.as_type.as_deref(),
+            })
+    }
+
+    /// Maps the field's type into the given language, honoring any type override.
+    fn mapped_type(&self, language: &str, ty: &Type, convert: impl Fn(&Type) -> String) -> String {
+        match self.type_override(language) {
+            Some(type_override) => type_override.to_string(),
+            None => convert(ty),
+        }
+    }
+
+    /// Picks out the serde attributes that change the serialized shape of a field,
+    /// quietly accepting everything else serde understands.
+    fn parse_serde_attr(&mut self, attr: &syn::Attribute) -> syn::Result<()> {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") || meta.path.is_ident("skip_serializing") {
+                self.skip = true;
+            } else if meta.path.is_ident("rename") {
+                if meta.input.peek(syn::Token![=]) {
+                    let value: syn::LitStr = meta.value()?.parse()?;
+                    self.rename = Some(value.value());
+                } else {
+                    // `rename(serialize = "...", deserialize = "...")`
+                    meta.parse_nested_meta(|nested| {
+                        if nested.path.is_ident("serialize") {
+                            let value: syn::LitStr = nested.value()?.parse()?;
+                            self.rename = Some(value.value());
+                            Ok(())
+                        } else {
+                            skip_meta_value(&nested)
+                        }
+                    })?;
+                }
+            } else if meta.path.is_ident("flatten") {
+                self.flatten = true;
+            } else if meta.path.is_ident("with") || meta.path.is_ident("serialize_with") {
+                let value: syn::LitStr = meta.value()?.parse()?;
+                self.serde_bytes = value.value() == "serde_bytes";
+            } else {
+                skip_meta_value(&meta)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+/// Consumes the value of an attribute entry we don't care about, e.g. `default = "..."`
+/// or `bound(serialize = "...")`.
+fn skip_meta_value(meta: &syn::meta::ParseNestedMeta) -> syn::Result<()> {
+    if meta.input.peek(syn::Token![=]) {
+        meta.value()?.parse::<syn::Expr>()?;
+    } else if meta.input.peek(syn::token::Paren) {
+        meta.parse_nested_meta(|nested| skip_meta_value(&nested))?;
+    }
+    Ok(())
+}
+
+/// Returns the struct tags of a Go field that follow the name in its `json` tag: `omitempty`
+/// when it's optional, and any `validate` tag.
+fn go_struct_tags(is_optional: bool, field_attributes: &FieldAttributes) -> String {
+    let mut tags = String::from(if is_optional { ",omitempty\"" } else { "\"" });
+
+    if let Some(validate) = &field_attributes.go_validate {
+        tags.push_str(&format!(" validate:\"{}\"", validate));
+    }
+
+    tags
+}
+
+/// Combines the output of several derived types into a single string.
+///
+/// Types are emitted in dependency order where possible, so a type is written after the
+/// listed types it references. Types that reference each other cyclically keep the order
+/// they were listed in.
+///
+/// The supported languages are the variants of `Language`: `Rust`, `TypeScript`, `Python`,
+/// `Go`, `Java`, `CSharp`, `Kotlin`, `Swift`, `Cpp`, `Flow`, `Elixir`, `OCaml` and `FSharp`.
+///
+/// # Example
+/// ```
+/// use struct_to_string::{struct_to_string_manifest, StructToString};
+///
+/// #[derive(StructToString)]
+/// struct User {
+///     address: Address,
+/// }
+///
+/// #[derive(StructToString)]
+/// struct Address {
+///     street: String,
+/// }
+///
+/// let manifest = struct_to_string_manifest! { [User, Address], lang = TypeScript };
+/// assert!(manifest.starts_with("interface Address {"));
+/// ```
+#[proc_macro]
+pub fn struct_to_string_manifest(input: TokenStream) -> TokenStream {
+    let manifest: ManifestInput = parse_macro_input!(input);
+
+    let language = manifest.language.to_string();
+    let method = match enabled_languages().find(|(variant, _)| *variant == language) {
+        Some((_, method)) => Ident::new(method, proc_macro2::Span::call_site()),
+        None => {
+            let languages: Vec<&str> = enabled_languages().map(|(variant, _)| *variant).collect();
+            return syn::Error::new(
+                manifest.language.span(),
+                format!(
+                    "unsupported language, expected one of `{}`",
+                    languages.join("`, `")
+                ),
+            )
+            .to_compile_error()
+            .into();
+        }
+    };
+
+    let names: Vec<String> = manifest
+        .types
+        .iter()
+        .map(|ty| match ty {
+            Type::Path(type_path) => type_path.path.segments.last().unwrap().ident.to_string(),
+            _ => quote!(#ty).to_string(),
+        })
+        .collect();
+    let types = &manifest.types;
+
+    let gen = quote! {
+        {
+            let names: &[&str] = &[#(#names),*];
+            let mut pending: Vec<(&str, Vec<&'static str>, String)> = vec![
+                #((#names, <#types>::referenced_types(), <#types>::#method())),*
+            ];
+            let mut emitted: Vec<&str> = Vec::new();
+            let mut outputs: Vec<String> = Vec::new();
+
+            while !pending.is_empty() {
+                // Pick the first type whose listed dependencies have all been emitted,
+                // falling back to list order when the remaining types form a cycle.
+                let next = pending
+                    .iter()
+                    .position(|(_, dependencies, _)| {
+                        dependencies
+                            .iter()
+                            .all(|dependency| emitted.contains(dependency) || !names.contains(dependency))
+                    })
+                    .unwrap_or(0);
+                let (name, _, output) = pending.remove(next);
+                emitted.push(name);
+                outputs.push(output.trim_end().to_string());
+            }
+
+            outputs.join("\n\n")
+        }
+    };
+
+    gen.into()
+}
+
+/// The input of `struct_to_string_manifest!`, e.g. `[A, B, C], lang = TypeScript`.
+struct ManifestInput {
+    types: Vec<Type>,
+    language: Ident,
+}
+
+impl Parse for ManifestInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let content;
+        syn::bracketed!(content in input);
+        let types = content
+            .parse_terminated(Type::parse, Token![,])?
+            .into_iter()
+            .collect();
+
+        input.parse::<Token![,]>()?;
+        let key: Ident = input.parse()?;
+        if key != "lang" {
+            return Err(syn::Error::new(key.span(), "expected `lang = <Language>`"));
+        }
+        input.parse::<Token![=]>()?;
+        let language = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+
+        Ok(ManifestInput { types, language })
+    }
+}
+
+/// Type names the converters map themselves, as opposed to custom types that are
+/// expected to be described elsewhere.
+const BUILTIN_TYPE_NAMES: &[&str] = &[
+    "i8",
+    "u8",
+    "i16",
+    "u16",
+    "i32",
+    "u32",
+    "i64",
+    "u64",
+    "i128",
+    "u128",
+    "isize",
+    "usize",
+    "f16",
+    "bf16",
+    "f32",
+    "f64",
+    "bool",
+    "String",
+    "ArrayString",
+    "Tz",
+    "char",
+    "str",
+    "Option",
+    "Result",
+    "PhantomData",
+    "Vec",
+    "TinyVec",
+    "SmallVec",
+    "BigInt",
+    "BigUint",
+    "BigDecimal",
+    "Box",
+    "Rc",
+    "Arc",
+    "Cow",
+    "HashMap",
+    "BTreeMap",
+    "IndexMap",
+    "DateTime",
+    "NaiveDateTime",
+    "NaiveDate",
+    "OffsetDateTime",
+    "SystemTime",
+    "Uuid",
+    "ByteBuf",
+];
+
+fn is_builtin_type(type_path: &syn::TypePath) -> bool {
+    let name = type_path.path.segments.last().unwrap().ident.to_string();
+    (BUILTIN_TYPE_NAMES.contains(&name.as_str()) && !is_unmapped_feature_type(&name))
+        || uom_quantity_unit(type_path).is_some()
+        || is_http_type(type_path)
+        || name.starts_with("NonZero")
+}
+
+/// Generic types of `std` and of common crates that the converters don't map. Their names
+/// alone mark them as such, since they're usually imported rather than spelled out.
+const LIBRARY_GENERIC_TYPE_NAMES: &[&str] = &[
+    "HashSet",
+    "BTreeSet",
+    "VecDeque",
+    "LinkedList",
+    "BinaryHeap",
+    "Cell",
+    "RefCell",
+    "OnceCell",
+    "OnceLock",
+    "Mutex",
+    "RwLock",
+    "Weak",
+    "Pin",
+    "Wrapping",
+    "Reverse",
+    "Range",
+    "RangeInclusive",
+    "IndexSet",
+];
+
+/// Whether the type comes from `std` or a common crate rather than being a custom one: either
+/// its path goes through one, e.g. `std::sync::Mutex<T>` or `parking_lot::Mutex<T>`, or it's
+/// named like one of `LIBRARY_GENERIC_TYPE_NAMES`.
+fn is_library_type(type_path: &syn::TypePath) -> bool {
+    const LIBRARY_CRATES: &[&str] = &[
+        "std",
+        "core",
+        "alloc",
+        "indexmap",
+        "hashbrown",
+        "parking_lot",
+        "smallvec",
+        "tinyvec",
+    ];
+    let segments = &type_path.path.segments;
+    LIBRARY_GENERIC_TYPE_NAMES.contains(&segments.last().unwrap().ident.to_string().as_str())
+        || (segments.len() > 1 && LIBRARY_CRATES.contains(&segments[0].ident.to_string().as_str()))
+}
+
+/// Whether the type is one of the `http` crate's that the converters map, `StatusCode`,
+/// `Method` or `HeaderMap`. Only a path through `http` is taken for one, e.g. `http::Method`
+/// or `axum::http::Method`, so that a type of one's own named like one is left alone.
+fn is_http_type(type_path: &syn::TypePath) -> bool {
+    let segments = &type_path.path.segments;
+    ["StatusCode", "Method", "HeaderMap"]
+        .contains(&segments.last().unwrap().ident.to_string().as_str())
+        && segments
+            .iter()
+            .take(segments.len() - 1)
+            .any(|module| module.ident == "http")
+}
+
+/// Whether the name belongs to a crate whose mapping is behind a disabled feature (`chrono`
+/// or `uuid`), in which case it's treated like any other custom type.
+fn is_unmapped_feature_type(name: &str) -> bool {
+    (!cfg!(feature = "chrono") && ["DateTime", "NaiveDateTime", "NaiveDate", "Tz"].contains(&name))
+        || (!cfg!(feature = "uuid") && name == "Uuid")
+}
+
+/// Returns the SI unit a `uom` quantity type (e.g. `uom::si::f64::Length`) stores its value in.
+/// Only a path through `uom` or its `si` module is taken for a quantity, so that a type of one's
+/// own named like one, e.g. `Time`, is left alone.
+fn uom_quantity_unit(type_path: &syn::TypePath) -> Option<&'static str> {
+    let segments = &type_path.path.segments;
+    if !segments
+        .iter()
+        .take(segments.len() - 1)
+        .any(|module| module.ident == "uom" || module.ident == "si")
+    {
+        return None;
+    }
+    let unit = match segments.last().unwrap().ident.to_string().as_str() {
+        "Length" => "m",
+        "Mass" => "kg",
+        "Time" => "s",
+        "ElectricCurrent" => "A",
+        "ThermodynamicTemperature" => "K",
+        "AmountOfSubstance" => "mol",
+        "LuminousIntensity" => "cd",
+        "Area" => "m²",
+        "Volume" => "m³",
+        "Velocity" => "m/s",
+        "Acceleration" => "m/s²",
+        "Force" => "N",
+        "Energy" => "J",
+        "Power" => "W",
+        "Pressure" => "Pa",
+        "Frequency" => "Hz",
+        "ElectricPotential" => "V",
+        "ElectricCharge" => "C",
+        "Angle" => "rad",
+        _ => return None,
+    };
+    Some(unit)
+}
+
+/// Returns a note about information lost when mapping the type, which the converters
+/// emit as a trailing comment on the field.
+fn rust_type_note(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap();
+            let name = last_segment.ident.to_string();
+            if let Some(unit) = uom_quantity_unit(type_path) {
+                return Some(format!("{} ({})", name, unit));
+            }
+            if name.starts_with("NonZero") {
+                return Some("nonzero".to_string());
+            }
+            if name == "f16" || name == "bf16" {
+                return Some(format!("{} (reduced precision)", name));
+            }
+            if name == "Tz" && cfg!(feature = "chrono") {
+                return Some("IANA time zone name".to_string());
+            }
+            if let syn::PathArguments::AngleBracketed(angle_bracketed_args) =
+                &last_segment.arguments
+            {
+                return angle_bracketed_args
+                    .args
+                    .iter()
+                    .find_map(|argument| match argument {
+                        syn::GenericArgument::Type(inner_type) => rust_type_note(inner_type),
+                        _ => None,
+                    });
+            }
+            None
+        }
+        Type::Array(array) => rust_type_note(&array.elem),
+        Type::Slice(slice) => rust_type_note(&slice.elem),
+        Type::Reference(reference) => rust_type_note(&reference.elem),
+        _ => None,
+    }
+}
+
+/// Returns the Rust integer type the type is or wraps, e.g. `u64` for `Option<u64>`.
+fn rust_integer_type(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap();
+            let name = last_segment.ident.to_string();
+            match name.as_str() {
+                "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "i128" | "u128"
+                | "isize" | "usize" => Some(name),
+                _ => match &last_segment.arguments {
+                    syn::PathArguments::AngleBracketed(angle_bracketed_args) => {
+                        angle_bracketed_args
+                            .args
+                            .iter()
+                            .find_map(|argument| match argument {
+                                syn::GenericArgument::Type(inner_type) => {
+                                    rust_integer_type(inner_type)
+                                }
+                                _ => None,
+                            })
+                    }
+                    _ => None,
+                },
+            }
+        }
+        Type::Array(array) => rust_integer_type(&array.elem),
+        Type::Slice(slice) => rust_integer_type(&slice.elem),
+        Type::Reference(reference) => rust_integer_type(&reference.elem),
+        _ => None,
+    }
+}
+
+/// Returns whether the type is the named type or wraps one, e.g. `Vec<char>` or `Option<char>`
+/// for `char`.
+fn rust_type_contains(ty: &Type, name: &str) -> bool {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap();
+            if last_segment.ident == name {
+                return true;
+            }
+            match &last_segment.arguments {
+                syn::PathArguments::AngleBracketed(angle_bracketed_args) => angle_bracketed_args
+                    .args
+                    .iter()
+                    .any(|argument| match argument {
+                        syn::GenericArgument::Type(inner_type) => {
+                            rust_type_contains(inner_type, name)
+                        }
+                        _ => false,
+                    }),
+                _ => false,
+            }
+        }
+        Type::Array(array) => rust_type_contains(&array.elem, name),
+        Type::Slice(slice) => rust_type_contains(&slice.elem, name),
+        Type::Reference(reference) => rust_type_contains(&reference.elem, name),
+        _ => false,
+    }
+}
+
+/// Collects the custom types (and their names) referenced by a field type, skipping
+/// builtins and the struct's own type parameters.
+fn collect_referenced_types(
+    ty: &Type,
+    type_params: &[String],
+    referenced: &mut Vec<(String, Type)>,
+) {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap();
+            let name = last_segment.ident.to_string();
+            // Types are told apart by their path and arguments, so `a::User` and `b::User` are
+            // both visited by the `*_recursive` methods.
+            if !is_builtin_type(type_path)
+                && !type_params.contains(&name)
+                && !referenced.iter().any(|(_, referenced_type)| {
+                    rust_type_string(referenced_type) == rust_type_string(ty)
+                })
+            {
+                referenced.push((name, ty.clone()));
+            }
+            // A `PhantomData` holds none of its type, so that type isn't referenced.
+            if last_segment.ident == "PhantomData" {
+                return;
+            }
+            if let syn::PathArguments::AngleBracketed(angle_bracketed_args) =
+                &last_segment.arguments
+            {
+                for argument in &angle_bracketed_args.args {
+                    if let syn::GenericArgument::Type(inner_type) = argument {
+                        collect_referenced_types(inner_type, type_params, referenced);
+                    }
+                }
+            }
+        }
+        Type::Array(array) => collect_referenced_types(&array.elem, type_params, referenced),
+        Type::Slice(slice) => collect_referenced_types(&slice.elem, type_params, referenced),
+        Type::Reference(reference) => {
+            collect_referenced_types(&reference.elem, type_params, referenced)
+        }
+        Type::Tuple(tuple) => {
+            for elem in &tuple.elems {
+                collect_referenced_types(elem, type_params, referenced);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Renders a type compactly, e.g. `HashMap<String,Vec<u8>>`, keeping only the spaces that
+/// separate two words, as in `&'a str` or `Box<dyn Error>`.
+fn rust_type_string(ty: &impl ToTokens) -> String {
+    let tokens = ty.to_token_stream().to_string();
+    let is_word = |c: char| c.is_alphanumeric() || c == '_';
+    let mut res = String::new();
+    let mut chars = tokens.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == ' ' {
+            let between_words = res.ends_with(is_word) && chars.peek().is_some_and(|&c| is_word(c));
+            if !between_words {
+                continue;
+            }
+        }
+        res.push(c);
+    }
+    res
+}
+
+/// Renders the generics of an `impl` block, e.g. `<'a, T: Clone + Default>`, or its where
+/// clause, the way they would be written by hand: like `rust_type_string`, but with a space
+/// after each comma and bound colon, and around each `+`.
+fn rust_generics_string(generics: &impl ToTokens) -> String {
+    let compact = rust_type_string(generics);
+    let mut res = String::new();
+    let mut chars = compact.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            ',' => res.push_str(", "),
+            '+' => res.push_str(" + "),
+            ':' if chars.peek() == Some(&':') => {
+                chars.next();
+                res.push_str("::");
+            }
+            ':' => res.push_str(": "),
+            _ => res.push(c),
+        }
+    }
+    res
+}
+
+/// Renders the head of an `impl` block for the type, e.g. `impl<'a, T: Clone> Foo<'a, T>`, or
+/// with a trait, e.g. `impl<T> std::fmt::Display for Foo<T> where T: Debug`. Unlike the
+/// declarations, it keeps every lifetime, bound and const parameter, so that it compiles.
+fn rust_impl_header(name: &Ident, generics: &syn::Generics, trait_for: &str) -> String {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let mut res = format!(
+        "impl{} {}{}{}",
+        rust_generics_string(&impl_generics),
+        trait_for,
+        name,
+        rust_generics_string(&ty_generics)
+    );
+    if let Some(where_clause) = where_clause {
+        res.push(' ');
+        res.push_str(rust_generics_string(where_clause).trim_end_matches(", "));
+    }
+    res
+}
+
+/// Renders an attribute the way it would be written by hand, e.g. `#[cfg(feature = "x")]`.
+fn attribute_to_string(attr: &syn::Attribute) -> String {
+    let tokens = attr.meta.to_token_stream().to_string();
+    let tokens = tokens
+        .replace(" (", "(")
+        .replace("( ", "(")
+        .replace(" )", ")")
+        .replace(" ,", ",");
+    format!("#[{}]", tokens)
+}
+
+/// Formats the attributes written above an item, each on its own line, with doc comments as
+/// `///` lines rather than the `#[doc = "..."]` they desugar to.
+fn item_attributes_to_string(attrs: &[syn::Attribute]) -> String {
+    let mut res = String::new();
+    for attr in attrs {
+        match &attr.meta {
+            syn::Meta::NameValue(syn::MetaNameValue {
+                path,
+                value:
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(doc),
+                        ..
+                    }),
+                ..
+            }) if path.is_ident("doc") => {
+                res.push_str(&format!("///{}\n", doc.value()));
+            }
+            _ => {
+                res.push_str(&attribute_to_string(attr));
+                res.push('\n');
+            }
+        }
+    }
+    res
+}
+
+/// Formats the `#[repr(...)]` attributes of an enum, each on its own line, since they fix the
+/// discriminants' integer type that protocol enums rely on.
+fn repr_attributes_to_string(attrs: &[syn::Attribute]) -> syn::Result<String> {
+    let mut res = String::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("repr")) {
+        let mut reprs = Vec::new();
+        attr.parse_nested_meta(|meta| {
+            reprs.push(meta.path.to_token_stream().to_string());
+            Ok(())
+        })?;
+        res.push_str(&format!("#[repr({})]\n", reprs.join(", ")));
+    }
+    Ok(res)
+}
+
+/// Formats a visibility with a trailing space, e.g. `pub ` or `pub(crate) `. Private items
+/// give an empty string.
+fn visibility_to_string(vis: &syn::Visibility) -> String {
+    match vis {
+        syn::Visibility::Inherited => String::new(),
+        _ => {
+            let tokens = vis.to_token_stream().to_string();
+            let tokens = tokens.replace(" (", "(").replace(" :: ", "::");
+            format!("{} ", tokens)
+        }
+    }
+}
+
+/// Formats a type name followed by its type parameters, e.g. `Wrapper<T>` or `Wrapper[T]`.
+fn generic_type_name(name: &str, type_params: &[String], open: &str, close: &str) -> String {
+    if type_params.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}{}{}{}", name, open, type_params.join(", "), close)
+    }
+}
+
+/// The `Vec<u8>` a `serde_bytes::ByteBuf` is mapped like, as both serialize as a list of bytes.
+fn byte_vec_type() -> Type {
+    syn::parse_quote!(Vec<u8>)
+}
+
+/// Replaces `Vec<u8>`, possibly wrapped in an `Option`, with `serde_bytes::ByteBuf`.
+fn byte_vec_as_byte_buf(ty: &Type) -> Type {
+    if let Type::Path(type_path) = ty {
+        let last_segment = type_path.path.segments.last().unwrap();
+        if let Some(inner_type) = first_type_argument(type_path) {
+            if last_segment.ident == "Option" {
+                let inner_type = byte_vec_as_byte_buf(inner_type);
+                return syn::parse_quote!(Option<#inner_type>);
+            }
+            if last_segment.ident == "Vec" && quote!(#inner_type).to_string() == "u8" {
+                return syn::parse_quote!(serde_bytes::ByteBuf);
+            }
+        }
+    }
+    ty.clone()
+}
+
+/// Replaces the nonzero integers within the type with the integer they hold, e.g. `NonZeroU32`
+/// or `NonZero<u32>` with `u32`.
+fn nonzero_as_integer(ty: &Type) -> Type {
+    replace_path_types(ty, &|type_path| {
+        let name = type_path.path.segments.last().unwrap().ident.to_string();
+        if name == "NonZero" {
+            return first_type_argument(type_path).cloned();
+        }
+        let integer = quote::format_ident!("{}", name.strip_prefix("NonZero")?.to_lowercase());
+        Some(syn::parse_quote!(#integer))
+    })
+}
+
+/// Replaces the string-like types within the type, `str`, `Box<str>`, `Rc<str>`, `Arc<str>`
+/// and `Cow<str>`, with `String`, and any other `Cow<T>` with `T`, as they all serialize like
+/// it. A `&str` becomes a `&String`, which every language reads through.
+fn string_like_as_string(ty: &Type) -> Type {
+    replace_path_types(ty, &|type_path| {
+        let name = type_path.path.segments.last().unwrap().ident.to_string();
+        let inner_type = first_type_argument(type_path);
+        let is_str = |ty: &Type| matches!(ty, Type::Path(path) if path.path.is_ident("str"));
+        match name.as_str() {
+            "str" => Some(syn::parse_quote!(String)),
+            "Box" | "Rc" | "Arc" | "Cow" if inner_type.is_some_and(is_str) => {
+                Some(syn::parse_quote!(String))
+            }
+            "Cow" => inner_type.map(string_like_as_string),
+            _ => None,
+        }
+    })
+}
+
+/// Rebuilds the type with each path type within it for which `replace` returns a type replaced
+/// by that type. The type arguments of the paths it keeps are visited in turn.
+fn replace_path_types(ty: &Type, replace: &impl Fn(&syn::TypePath) -> Option<Type>) -> Type {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            if let Some(replacement) = replace(type_path) {
+                return replacement;
+            }
+            let mut type_path = type_path.clone();
+            let last_segment = type_path.path.segments.last_mut().unwrap();
+            if let syn::PathArguments::AngleBracketed(angle_bracketed_args) =
+                &mut last_segment.arguments
+            {
+                for argument in angle_bracketed_args.args.iter_mut() {
+                    if let syn::GenericArgument::Type(argument_type) = argument {
+                        *argument_type = replace_path_types(argument_type, replace);
+                    }
+                }
+            }
+            Type::Path(type_path)
+        }
+        Type::Reference(reference) => {
+            let mut reference = reference.clone();
+            *reference.elem = replace_path_types(&reference.elem, replace);
+            Type::Reference(reference)
+        }
+        Type::Array(array) => {
+            let mut array = array.clone();
+            *array.elem = replace_path_types(&array.elem, replace);
+            Type::Array(array)
+        }
+        Type::Slice(slice) => {
+            let mut slice = slice.clone();
+            *slice.elem = replace_path_types(&slice.elem, replace);
+            Type::Slice(slice)
+        }
+        Type::Tuple(tuple) => {
+            let mut tuple = tuple.clone();
+            for elem in tuple.elems.iter_mut() {
+                *elem = replace_path_types(elem, replace);
+            }
+            Type::Tuple(tuple)
+        }
+        _ => ty.clone(),
+    }
+}
+
+/// Replaces the type with `String`, keeping an `Option` around it.
+fn constrained_string(ty: &Type) -> Type {
+    if let Type::Path(type_path) = ty {
+        if type_path.path.segments.last().unwrap().ident == "Option" {
+            return syn::parse_quote!(Option<String>);
+        }
+    }
+    syn::parse_quote!(String)
+}
+
+/// Returns whether the type is an error trait object such as `dyn std::error::Error`, possibly
+/// behind a `Box`, `Rc`, `Arc` or reference.
+fn is_dyn_error(ty: &Type) -> bool {
+    match ty {
+        Type::TraitObject(trait_object) => trait_object.bounds.iter().any(|bound| match bound {
+            syn::TypeParamBound::Trait(trait_bound) => {
+                trait_bound.path.segments.last().unwrap().ident == "Error"
+            }
+            _ => false,
+        }),
+        Type::Path(type_path) => {
+            let last_segment = &type_path.path.segments.last().unwrap().ident;
+            (last_segment == "Box" || last_segment == "Rc" || last_segment == "Arc")
+                && first_type_argument(type_path).is_some_and(is_dyn_error)
+        }
+        Type::Reference(reference) => is_dyn_error(&reference.elem),
+        _ => false,
+    }
+}
+
+/// Returns the first type argument of a path such as `Box<T>`.
+fn first_type_argument(type_path: &syn::TypePath) -> Option<&Type> {
+    let arguments = &type_path.path.segments.last().unwrap().arguments;
+    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
+        return angle_bracketed_args
+            .args
+            .iter()
+            .find_map(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+    }
+    None
+}
+
+/// Returns the first const generic argument of a type, e.g. the `16` of `ArrayString<16>`, when
+/// it's an integer literal.
+fn const_type_argument(type_path: &syn::TypePath) -> Option<String> {
+    let arguments = &type_path.path.segments.last().unwrap().arguments;
+    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
+        return angle_bracketed_args
+            .args
+            .iter()
+            .find_map(|argument| match argument {
+                syn::GenericArgument::Const(syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(capacity),
+                    ..
+                })) => Some(capacity.base10_digits().to_string()),
+                _ => None,
+            });
+    }
+    None
+}
+
+/// Returns the element type of a `Vec<T>` or of a `Vec`-like collection, ignoring its capacity:
+/// `heapless::Vec<T, N>`, or `tinyvec::TinyVec<[T; N]>` and `smallvec::SmallVec<[T; N]>`, which
+/// take their inline storage as an array type.
+fn vec_element_type(type_path: &syn::TypePath) -> Option<&Type> {
+    match first_type_argument(type_path)? {
+        Type::Array(array) if type_path.path.segments.last().unwrap().ident != "Vec" => {
+            Some(&array.elem)
+        }
+        ty => Some(ty),
+    }
+}
+
+/// Returns the key and value types of a map such as `HashMap<K, V>`.
+fn map_key_value_types(type_path: &syn::TypePath) -> Option<(&Type, &Type)> {
+    let arguments = &type_path.path.segments.last().unwrap().arguments;
+    if let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments {
+        let mut types = angle_bracketed_args
+            .args
+            .iter()
+            .filter_map(|argument| match argument {
+                syn::GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            });
+        if let (Some(key_type), Some(value_type)) = (types.next(), types.next()) {
+            return Some((key_type, value_type));
+        }
+    }
+    None
+}
+
+/// Maps a map type to a TypeScript `Record` keyed by a union of string literals, looking
+/// through an outer `Option`. Other types get the regular TypeScript mapping.
+fn rust_type_to_ts_keyed_record(ty: &Type, keys: &[String]) -> String {
+    if let Type::Path(type_path) = ty {
+        let last_segment = type_path.path.segments.last().unwrap();
+        match last_segment.ident.to_string().as_str() {
+            "HashMap" | "BTreeMap" | "IndexMap" => {
+                if let Some((_, value_type)) = map_key_value_types(type_path) {
+                    let key_union: Vec<String> =
+                        keys.iter().map(|key| format!("\"{}\"", key)).collect();
+                    return format!(
+                        "Record<{}, {}>",
+                        key_union.join(" | "),
+                        rust_type_to_ts_type(value_type)
+                    );
+                }
+            }
+            "Option" => {
+                if let Some(inner_type) = first_type_argument(type_path) {
+                    return format!(
+                        "{} | null",
+                        rust_type_to_ts_keyed_record(strip_nested_options(inner_type), keys)
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+    rust_type_to_ts_type(ty)
+}
+
+/// Shortens every path in the type to its last segment, e.g. `crate::models::Address` to
+/// `Address` and `std::collections::HashMap<String, a::B>` to `HashMap<String, B>`.
+fn short_type_names(ty: &Type) -> Type {
+    match ty {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            let mut last_segment = type_path.path.segments.last().unwrap().clone();
+            if let syn::PathArguments::AngleBracketed(angle_bracketed_args) =
+                &mut last_segment.arguments
+            {
+                for argument in angle_bracketed_args.args.iter_mut() {
+                    if let syn::GenericArgument::Type(argument_type) = argument {
+                        *argument_type = short_type_names(argument_type);
+                    }
+                }
+            }
+            syn::parse_quote!(#last_segment)
+        }
+        Type::Reference(reference) => {
+            let mut reference = reference.clone();
+            *reference.elem = short_type_names(&reference.elem);
+            Type::Reference(reference)
+        }
+        Type::Array(array) => {
+            let mut array = array.clone();
+            *array.elem = short_type_names(&array.elem);
+            Type::Array(array)
+        }
+        Type::Slice(slice) => {
+            let mut slice = slice.clone();
+            *slice.elem = short_type_names(&slice.elem);
+            Type::Slice(slice)
+        }
+        Type::Tuple(tuple) => {
+            let mut tuple = tuple.clone();
+            for elem in tuple.elems.iter_mut() {
+                *elem = short_type_names(elem);
+            }
+            Type::Tuple(tuple)
+        }
+        _ => ty.clone(),
+    }
+}
+
+/// Collapses nested options such as `Option<Option<T>>` down to `T`, so that the
+/// outer nullability is only applied once.
+fn strip_nested_options(ty: &Type) -> &Type {
+    if let Type::Path(type_path) = ty {
+        let last_segment = type_path.path.segments.last().unwrap();
+        if last_segment.ident == "Option" {
+            if let Some(inner_type) = first_type_argument(type_path) {
+                return strip_nested_options(inner_type);
+            }
+        }
+    }
+    ty
+}
+
+/// The ways the TypeScript type of a field can be customized.
+#[derive(Clone, Copy, Default)]
+struct TsTypeOptions<'a> {
+    /// The `ResultStrategy` variant `Result<T, E>` is typed as, the tagged object when empty.
+    result: &'a str,
+    /// The generic type `Option<T>` is wrapped in, e.g. fp-ts' `Option`, instead of `T | null`.
+    option_wrapper: Option<&'a str>,
+}
+
+fn rust_type_to_ts_type(ty: &Type) -> String {
+    rust_type_to_ts_type_with(ty, TsTypeOptions::default())
+}
+
+/// Like `rust_type_to_ts_type`, customized by `options`.
+fn rust_type_to_ts_type_with(ty: &Type, options: TsTypeOptions) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "isize" | "usize" => {
+                    "number"
+                }
+                "f16" | "bf16" | "f32" | "f64" => "number",
+                "bool" => "boolean",
+                "String" | "ArrayString" | "Tz" => "string",
+                "char" => "string",
+                "BigInt" | "BigUint" => "bigint",
+                "BigDecimal" => "string",
+                "DateTime" | "NaiveDateTime" | "NaiveDate" | "OffsetDateTime" | "SystemTime" => {
+                    "string"
+                }
+                "Uuid" => "string",
+                "ByteBuf" => return rust_type_to_ts_type_with(&byte_vec_type(), options),
+                "StatusCode" if is_http_type(type_path) => "number",
+                "Method" if is_http_type(type_path) => "string",
+                "HeaderMap" if is_http_type(type_path) => "Record<string, string>",
+                _ if uom_quantity_unit(type_path).is_some() => "number",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        let inner_type =
+                            rust_type_to_ts_type_with(strip_nested_options(inner_type), options);
+                        return match options.option_wrapper {
+                            Some(wrapper) => format!("{}<{}>", wrapper, inner_type),
+                            None => format!("{} | null", inner_type),
+                        };
+                    }
+                    "any"
+                }
+                // serde writes a `Result` as an object keyed by its variant.
+                "Result" => {
+                    if let Some((ok_type, err_type)) = map_key_value_types(type_path) {
+                        let ok_type = rust_type_to_ts_type_with(ok_type, options);
+                        let err_type = rust_type_to_ts_type_with(err_type, options);
+                        return match options.result {
+                            "Union" => format!("{} | {}", ok_type, err_type),
+                            "Unwrap" => ok_type,
+                            _ => format!("{{ Ok: {} }} | {{ Err: {} }}", ok_type, err_type),
+                        };
+                    }
+                    "any"
+                }
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "Record<{}, {}>",
+                            rust_type_to_ts_type_with(key_type, options),
+                            rust_type_to_ts_type_with(value_type, options)
+                        );
+                    }
+                    "Record<string, any>"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_ts_type_with(inner_type, options);
+                    }
+                    "any"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return ts_array_type(inner_type, options);
+                    }
+                    "any[]"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => ts_array_type(&array.elem, options),
+        Type::Slice(slice) => ts_array_type(&slice.elem, options),
+        Type::Reference(reference) => rust_type_to_ts_type_with(&reference.elem, options),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "null".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple
+                .elems
+                .iter()
+                .map(|elem| rust_type_to_ts_type_with(elem, options))
+                .collect();
+            format!("[{}]", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Error".to_string(),
+        _ => "any".to_string(), // Fallback to 'any' for unsupported or complex types.
+    }
+}
+
+/// Returns the TypeScript array of the element type, parenthesizing unions such as
+/// `(number | null)[]`.
+fn ts_array_type(inner_type: &Type, options: TsTypeOptions) -> String {
+    let inner_type = rust_type_to_ts_type_with(inner_type, options);
+    if inner_type.contains(" | ") {
+        format!("({})[]", inner_type)
+    } else {
+        format!("{}[]", inner_type)
+    }
+}
+
+/// Returns the Zod schema validating the type. `Option<T>` is `.nullable()`, and the field
+/// itself is made `.optional()` by the caller.
+fn rust_type_to_zod_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "isize" | "usize"
+                | "f16" | "bf16" | "f32" | "f64" => "z.number()",
+                "bool" => "z.boolean()",
+                "String" | "ArrayString" | "Tz" => "z.string()",
+                "char" => "z.string().length(1)",
+                "BigInt" | "BigUint" => "z.bigint()",
+                "BigDecimal" => "z.string()",
+                "DateTime" | "NaiveDateTime" | "OffsetDateTime" | "SystemTime" => {
+                    "z.string().datetime()"
+                }
+                "NaiveDate" => "z.string().date()",
+                "Uuid" => "z.string().uuid()",
+                "ByteBuf" => return rust_type_to_zod_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "z.number()",
+                "Method" if is_http_type(type_path) => "z.string()",
+                "HeaderMap" if is_http_type(type_path) => "z.record(z.string(), z.string())",
+                _ if uom_quantity_unit(type_path).is_some() => "z.number()",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "{}.nullable()",
+                            rust_type_to_zod_type(strip_nested_options(inner_type))
+                        );
+                    }
+                    "z.any()"
+                }
+                // serde writes a `Result` as an object keyed by its variant.
+                "Result" => {
+                    if let Some((ok_type, err_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "z.union([z.object({{ Ok: {} }}), z.object({{ Err: {} }})])",
+                            rust_type_to_zod_type(ok_type),
+                            rust_type_to_zod_type(err_type)
+                        );
+                    }
+                    "z.any()"
+                }
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "z.record({}, {})",
+                            rust_type_to_zod_type(key_type),
+                            rust_type_to_zod_type(value_type)
+                        );
+                    }
+                    "z.record(z.string(), z.any())"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_zod_type(inner_type);
+                    }
+                    "z.any()"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("z.array({})", rust_type_to_zod_type(inner_type));
+                    }
+                    "z.array(z.any())"
+                }
+                _ => &last_segment, // Fallback to the schema of the same name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => format!("z.array({})", rust_type_to_zod_type(&array.elem)),
+        Type::Slice(slice) => format!("z.array({})", rust_type_to_zod_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_zod_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "z.null()".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_zod_type).collect();
+            format!("z.tuple([{}])", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "z.instanceof(Error)".to_string(),
+        _ => "z.any()".to_string(), // Fallback to 'z.any()' for unsupported or complex types.
+    }
+}
+
+fn rust_type_to_flow_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "isize" | "usize" => {
+                    "number"
+                }
+                "f16" | "bf16" | "f32" | "f64" => "number",
+                "bool" => "boolean",
+                "String" | "ArrayString" | "Tz" => "string",
+                "char" => "string",
+                "BigInt" | "BigUint" => "bigint",
+                "BigDecimal" => "string",
+                "DateTime" | "NaiveDateTime" | "NaiveDate" | "OffsetDateTime" | "SystemTime" => {
+                    "string"
+                }
+                "Uuid" => "string",
+                "ByteBuf" => return rust_type_to_flow_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "number",
+                "Method" if is_http_type(type_path) => "string",
+                "HeaderMap" if is_http_type(type_path) => "{ [key: string]: string }",
+                _ if uom_quantity_unit(type_path).is_some() => "number",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        let inner_type = rust_type_to_flow_type(strip_nested_options(inner_type));
+                        // `?` binds tighter than `|`, so unions are parenthesized.
+                        if inner_type.contains(" | ") {
+                            return format!("?({})", inner_type);
+                        }
+                        return format!("?{}", inner_type);
+                    }
+                    "mixed"
+                }
+                // serde writes a `Result` as an object keyed by its variant.
+                "Result" => {
+                    if let Some((ok_type, err_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "{{| Ok: {} |}} | {{| Err: {} |}}",
+                            rust_type_to_flow_type(ok_type),
+                            rust_type_to_flow_type(err_type)
+                        );
+                    }
+                    "mixed"
+                }
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "{{ [key: {}]: {} }}",
+                            rust_type_to_flow_type(key_type),
+                            rust_type_to_flow_type(value_type)
+                        );
+                    }
+                    "{ [key: string]: mixed }"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_flow_type(inner_type);
+                    }
+                    "mixed"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("Array<{}>", rust_type_to_flow_type(inner_type));
+                    }
+                    "Array<mixed>"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => format!("Array<{}>", rust_type_to_flow_type(&array.elem)),
+        Type::Slice(slice) => format!("Array<{}>", rust_type_to_flow_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_flow_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "null".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_flow_type).collect();
+            format!("[{}]", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Error".to_string(),
+        _ => "mixed".to_string(), // Fallback to 'mixed' for unsupported or complex types.
+    }
+}
+
+fn rust_type_to_elixir_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                // Left as the module of a custom type, like the fallback below.
+                feature_type if is_unmapped_feature_type(feature_type) => {
+                    return format!("{}.t()", last_segment)
+                }
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => "integer()",
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => "integer()",
+                "f16" | "bf16" | "f32" | "f64" => "float()",
+                "bool" => "boolean()",
+                "String" | "ArrayString" | "Tz" => "String.t()",
+                "char" => "String.t()",
+                "BigInt" | "BigUint" => "integer()",
+                "BigDecimal" => "Decimal.t()",
+                "DateTime" | "OffsetDateTime" | "SystemTime" => "DateTime.t()",
+                "NaiveDateTime" => "NaiveDateTime.t()",
+                "NaiveDate" => "Date.t()",
+                "Uuid" => "String.t()",
+                "ByteBuf" => return rust_type_to_elixir_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "integer()",
+                "Method" if is_http_type(type_path) => "String.t()",
+                "HeaderMap" if is_http_type(type_path) => "%{optional(String.t()) => String.t()}",
+                _ if uom_quantity_unit(type_path).is_some() => "float()",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "{} | nil",
+                            rust_type_to_elixir_type(strip_nested_options(inner_type))
+                        );
+                    }
+                    "any()"
+                }
+                // serde writes a `Result` as an object keyed by its variant.
+                "Result" => {
+                    if let Some((ok_type, err_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "%{{Ok: {}}} | %{{Err: {}}}",
+                            rust_type_to_elixir_type(ok_type),
+                            rust_type_to_elixir_type(err_type)
+                        );
+                    }
+                    "map()"
+                }
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "%{{optional({}) => {}}}",
+                            rust_type_to_elixir_type(key_type),
+                            rust_type_to_elixir_type(value_type)
+                        );
+                    }
+                    "map()"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_elixir_type(inner_type);
+                    }
+                    "any()"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("[{}]", rust_type_to_elixir_type(inner_type));
+                    }
+                    "list()"
+                }
+                // Custom types are assumed to be modules declaring their own `t` type, as the
+                // output of this crate does.
+                _ => return format!("{}.t()", last_segment),
+            }
+            .to_string()
+        }
+        Type::Array(array) => format!("[{}]", rust_type_to_elixir_type(&array.elem)),
+        Type::Slice(slice) => format!("[{}]", rust_type_to_elixir_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_elixir_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "nil".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_elixir_type).collect();
+            format!("{{{}}}", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Exception.t()".to_string(),
+        _ => "any()".to_string(), // Fallback to `any()` for unsupported or complex types.
+    }
+}
+
+/// Writes a name as an Elixir atom, e.g. `:user_id`, quoting it when it isn't a plain
+/// identifier, e.g. `:"user-id"`.
+fn elixir_atom(name: &str) -> String {
+    format!(":{}", elixir_atom_name(name))
+}
+
+/// Writes a name as the key of an Elixir keyword list or map, e.g. `user_id:`.
+fn elixir_key(name: &str) -> String {
+    format!("{}:", elixir_atom_name(name))
+}
+
+fn elixir_atom_name(name: &str) -> String {
+    let quote = elixir_atom_quote(name);
+    format!("{}{}{}", quote, name, quote)
+}
+
+/// The quote an atom or key needs around `name`, or nothing when it's a plain identifier.
+fn elixir_atom_quote(name: &str) -> &'static str {
+    let plain = name
+        .chars()
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_')
+        && name.chars().all(|c| c.is_alphanumeric() || c == '_');
+    if plain {
+        ""
+    } else {
+        "\""
+    }
+}
+
+fn rust_type_to_ocaml_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => {
+                    return ocaml_type_name(&last_segment)
+                }
+                "i8" | "i16" | "i32" | "isize" => "int",
+                "u8" | "u16" | "u32" | "usize" => "int",
+                "i64" | "u64" => "int64",
+                "i128" | "u128" | "BigInt" | "BigUint" => "Z.t",
+                "f16" | "bf16" | "f32" | "f64" => "float",
+                "bool" => "bool",
+                "String" | "ArrayString" | "Tz" => "string",
+                "char" => "string",
+                "BigDecimal" => "string",
+                "DateTime" | "NaiveDateTime" | "NaiveDate" | "OffsetDateTime" | "SystemTime" => {
+                    "string"
+                }
+                "Uuid" => "string",
+                "ByteBuf" => return rust_type_to_ocaml_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "int",
+                "Method" if is_http_type(type_path) => "string",
+                "HeaderMap" if is_http_type(type_path) => "(string * string) list",
+                _ if uom_quantity_unit(type_path).is_some() => "float",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "{} option",
+                            ocaml_type_argument(strip_nested_options(inner_type))
+                        );
+                    }
+                    "Yojson.Safe.t"
+                }
+                "Result" => {
+                    if let Some((ok_type, err_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "({}, {}) result",
+                            rust_type_to_ocaml_type(ok_type),
+                            rust_type_to_ocaml_type(err_type)
+                        );
+                    }
+                    "Yojson.Safe.t"
+                }
+                // An association list, as OCaml has no map type literal.
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "({} * {}) list",
+                            ocaml_type_argument(key_type),
+                            ocaml_type_argument(value_type)
+                        );
+                    }
+                    "(string * Yojson.Safe.t) list"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_ocaml_type(inner_type);
+                    }
+                    "Yojson.Safe.t"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("{} list", ocaml_type_argument(inner_type));
+                    }
+                    "Yojson.Safe.t list"
+                }
+                _ => return ocaml_type_name(&last_segment), // Custom types are named like this crate names them.
+            }
+            .to_string()
+        }
+        Type::Array(array) => format!("{} array", ocaml_type_argument(&array.elem)),
+        Type::Slice(slice) => format!("{} array", ocaml_type_argument(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_ocaml_type(&reference.elem),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "unit".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(ocaml_type_argument).collect();
+            format!("({})", types.join(" * "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "exn".to_string(),
+        _ => "Yojson.Safe.t".to_string(), // Fallback to any JSON value for unsupported types.
+    }
+}
+
+/// Maps a type to OCaml as the argument of a postfix type constructor like `list`,
+/// parenthesizing tuples, e.g. `(int * string) list`, which would otherwise bind wrong.
+fn ocaml_type_argument(ty: &Type) -> String {
+    let ocaml_type = rust_type_to_ocaml_type(ty);
+    if ocaml_type.contains(" * ") && !ocaml_type.starts_with('(') {
+        format!("({})", ocaml_type)
+    } else {
+        ocaml_type
+    }
+}
+
+/// Converts a type name to the lowercase `snake_case` OCaml type names are written in, e.g.
+/// `UserAccount` to `user_account`.
+fn ocaml_type_name(name: &str) -> String {
+    screaming_snake_case(name).to_lowercase()
+}
+
+fn rust_type_to_fsharp_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" | "i16" | "i32" | "u8" | "u16" => "int",
+                "u32" | "i64" | "isize" => "int64",
+                "u64" | "usize" => "uint64",
+                "i128" | "u128" | "BigInt" | "BigUint" => "bigint",
+                "f16" | "bf16" | "f32" | "f64" => "float",
+                "bool" => "bool",
+                "String" | "ArrayString" | "Tz" => "string",
+                "char" => "char",
+                "BigDecimal" => "decimal",
+                "DateTime" | "OffsetDateTime" | "SystemTime" => "DateTimeOffset",
+                "NaiveDateTime" => "DateTime",
+                "NaiveDate" => "DateOnly",
+                "Uuid" => "Guid",
+                "ByteBuf" => return rust_type_to_fsharp_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "int",
+                "Method" if is_http_type(type_path) => "string",
+                "HeaderMap" if is_http_type(type_path) => "Map<string, string>",
+                _ if uom_quantity_unit(type_path).is_some() => "float",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "{} option",
+                            fsharp_type_argument(strip_nested_options(inner_type))
+                        );
+                    }
+                    "obj option"
+                }
+                "Result" => {
+                    if let Some((ok_type, err_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "Result<{}, {}>",
+                            rust_type_to_fsharp_type(ok_type),
+                            rust_type_to_fsharp_type(err_type)
+                        );
+                    }
+                    "obj"
+                }
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "Map<{}, {}>",
+                            rust_type_to_fsharp_type(key_type),
+                            rust_type_to_fsharp_type(value_type)
+                        );
+                    }
+                    "Map<obj, obj>"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_fsharp_type(inner_type);
+                    }
+                    "obj"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("{} list", fsharp_type_argument(inner_type));
+                    }
+                    "obj list"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => format!("{} array", fsharp_type_argument(&array.elem)),
+        Type::Slice(slice) => format!("{} array", fsharp_type_argument(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_fsharp_type(&reference.elem),
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "unit".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(fsharp_type_argument).collect();
+            types.join(" * ")
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "exn".to_string(),
+        _ => "obj".to_string(), // Fallback to 'obj' for unsupported or complex types.
+    }
+}
+
+/// Maps a type to F# as the argument of a postfix type constructor like `list`,
+/// parenthesizing tuples, e.g. `(int * string) list`, which would otherwise bind wrong.
+fn fsharp_type_argument(ty: &Type) -> String {
+    let fsharp_type = rust_type_to_fsharp_type(ty);
+    if fsharp_type.contains(" * ") {
+        format!("({})", fsharp_type)
+    } else {
+        fsharp_type
+    }
+}
+
+fn rust_type_to_python_type(ty: &Type) -> String {
+    rust_type_to_python_type_with_syntax(ty, PythonSyntax::Typing)
+}
+
+/// How generic Python types are spelled.
+#[derive(Clone, Copy, PartialEq)]
+enum PythonSyntax {
+    /// The `typing` aliases, e.g. `List[int]` and `Dict[str, int]`.
+    Typing,
+    /// The builtin generics of Python 3.9, e.g. `list[int]` and `dict[str, int]`.
+    BuiltinGenerics,
+    /// The builtin generics along with the `X | None` unions of Python 3.10.
+    Modern,
+}
+
+fn rust_type_to_python_type_with_syntax(ty: &Type, syntax: PythonSyntax) -> String {
+    let (list, dict, tuple_name) = match syntax {
+        PythonSyntax::Typing => ("List", "Dict", "Tuple"),
+        PythonSyntax::BuiltinGenerics | PythonSyntax::Modern => ("list", "dict", "tuple"),
+    };
+    let convert = |ty: &Type| rust_type_to_python_type_with_syntax(ty, syntax);
+
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "isize" | "usize" => {
+                    "int"
+                }
+                "f16" | "bf16" | "f32" | "f64" => "float",
+                "bool" => "bool",
+                "String" | "ArrayString" | "Tz" => "str",
+                "char" => "str",
+                "BigInt" | "BigUint" => "int",
+                "BigDecimal" => "Decimal",
+                "DateTime" | "NaiveDateTime" | "OffsetDateTime" | "SystemTime" => {
+                    "datetime.datetime"
+                }
+                "NaiveDate" => "datetime.date",
+                "Uuid" => "uuid.UUID",
+                "ByteBuf" => return rust_type_to_python_type_with_syntax(&byte_vec_type(), syntax),
+                "StatusCode" if is_http_type(type_path) => "int",
+                "Method" if is_http_type(type_path) => "str",
+                "HeaderMap" if is_http_type(type_path) => return format!("{}[str, str]", dict),
+                _ if uom_quantity_unit(type_path).is_some() => "float",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        let inner_type = convert(strip_nested_options(inner_type));
+                        return match syntax {
+                            PythonSyntax::Modern => format!("{} | None", inner_type),
+                            _ => format!("Optional[{}]", inner_type),
+                        };
+                    }
+                    "any"
+                }
+                // serde writes a `Result` as an object keyed by its variant.
+                "Result" => {
+                    if let Some((ok_type, err_type)) = map_key_value_types(type_path) {
+                        let ok_type = format!("{}[Literal[\"Ok\"], {}]", dict, convert(ok_type));
+                        let err_type = format!("{}[Literal[\"Err\"], {}]", dict, convert(err_type));
+                        return match syntax {
+                            PythonSyntax::Modern => format!("{} | {}", ok_type, err_type),
+                            _ => format!("Union[{}, {}]", ok_type, err_type),
+                        };
+                    }
+                    "Any"
+                }
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!("{}[{}, {}]", dict, convert(key_type), convert(value_type));
+                    }
+                    return format!("{}[any, any]", dict);
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return convert(inner_type);
+                    }
+                    "Any"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("{}[{}]", list, convert(inner_type));
+                    }
+                    "any[]"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => format!("{}[{}]", list, convert(&array.elem)),
+        Type::Slice(slice) => format!("{}[{}]", list, convert(&slice.elem)),
+        Type::Reference(reference) => convert(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "None".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(convert).collect();
+            format!("{}[{}]", tuple_name, types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Exception".to_string(),
+        _ => "any".to_string(), // Fallback to 'any' for unsupported or complex types.
+    }
+}
+
+/// Maps a Rust type to Go, along with a note on what Go can't express, such as tuples, for a
+/// comment after the field. The note is kept apart so that a nested type, e.g. the key of
+/// `HashMap<(i32, i32), String>`, doesn't end the Go type early.
+fn rust_type_to_go_type(ty: &Type) -> (String, Option<String>) {
+    let mut notes = Vec::new();
+    let go_type = go_type_with_notes(ty, &mut notes);
+    let note = (!notes.is_empty()).then(|| notes.join("; "));
+    (go_type, note)
+}
+
+fn go_type_with_notes(ty: &Type, notes: &mut Vec<String>) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" => "int8",
+                "u8" => "uint8",
+                "i16" => "int16",
+                "u16" => "uint16",
+                "i32" => "int32",
+                "u32" => "uint32",
+                "i64" => "int64",
+                "u64" => "uint64",
+                "isize" => "int",
+                "usize" => "uint",
+                "i128" => "big.Int",
+                "u128" => "big.Int",
+                "f16" | "bf16" | "f32" => "float32",
+                "f64" => "float64",
+                "bool" => "bool",
+                "String" | "ArrayString" | "Tz" => "string",
+                "char" => "rune",
+                "&str" => "string",
+                "BigInt" | "BigUint" => "big.Int",
+                "BigDecimal" => "big.Float",
+                "DateTime" | "NaiveDateTime" | "NaiveDate" | "OffsetDateTime" | "SystemTime" => {
+                    "time.Time"
+                }
+                "Uuid" => "string",
+                "ByteBuf" => return go_type_with_notes(&byte_vec_type(), notes),
+                "StatusCode" if is_http_type(type_path) => "int",
+                "Method" if is_http_type(type_path) => "string",
+                "HeaderMap" if is_http_type(type_path) => "map[string]string",
+                _ if uom_quantity_unit(type_path).is_some() => "float64",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        // An `error` is an interface, which is nil-able already.
+                        if is_dyn_error(inner_type) {
+                            return "error".to_string();
+                        }
+                        return format!(
+                            "*{}",
+                            go_type_with_notes(strip_nested_options(inner_type), notes)
+                        );
+                    }
+                    "any"
+                }
+                // An object keyed by `Ok` or `Err`, which the field's comment spells out.
+                "Result" => "map[string]any",
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "map[{}]{}",
+                            go_type_with_notes(key_type, notes),
+                            go_type_with_notes(value_type, notes)
+                        );
+                    }
+                    "map[any]any"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return go_type_with_notes(inner_type, notes);
+                    }
+                    "any"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("[]{}", go_type_with_notes(inner_type, notes));
+                    }
+                    "any[]"
+                }
+                // A custom generic type is instantiated with its type arguments, e.g.
+                // `Page[User]`. Lifetimes and const arguments have no Go equivalent. Generic
+                // library types, such as `HashSet<T>` or `Mutex<T>`, aren't Go types, so they
+                // keep their bare name, as in the other languages.
+                _ if first_type_argument(type_path).is_some() && !is_library_type(type_path) => {
+                    let arguments = &type_path.path.segments.last().unwrap().arguments;
+                    let syn::PathArguments::AngleBracketed(angle_bracketed_args) = arguments else {
+                        unreachable!("a type with a type argument has angle brackets");
+                    };
+                    let type_arguments: Vec<String> = angle_bracketed_args
+                        .args
+                        .iter()
+                        .filter_map(|argument| match argument {
+                            syn::GenericArgument::Type(ty) => Some(go_type_with_notes(ty, notes)),
+                            _ => None,
+                        })
+                        .collect();
+                    return format!("{}[{}]", last_segment, type_arguments.join(", "));
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => {
+            let inner_type = go_type_with_notes(&array.elem, notes);
+            match &array.len {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(lit_int),
+                    ..
+                }) => format!("[{}]{}", lit_int.base10_digits(), inner_type),
+                // Go generics have no const parameters, so `[T; N]` falls back to a slice, as
+                // does any length that isn't a plain literal.
+                _ => format!("[]{}", inner_type),
+            }
+        }
+        Type::Slice(slice) => format!("[]{}", go_type_with_notes(&slice.elem, notes)),
+        Type::Reference(reference) => go_type_with_notes(&reference.elem, notes),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => {
+            notes.push("unit type, always null".to_string());
+            "*struct{}".to_string()
+        }
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple
+                .elems
+                .iter()
+                .map(|elem| go_type_with_notes(elem, notes))
+                .collect();
+            notes.push(format!(
+                "CANNOT CONVERT THIS TO THE GO PROGRAMMING LANGUAGE. TUPLES ARE UNSUPPORTED BY GO: ({})",
+                types.join(", ")
+            ));
+            "struct{}".to_string()
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "error".to_string(),
+        _ => "any".to_string(), // Fallback to 'any' for unsupported or complex types.
+    }
+}
+
+/// Returns the Go packages a mapped Go type depends on.
+fn go_imports_for_type(go_type: &str) -> Vec<&'static str> {
+    let mut imports = Vec::new();
+    if go_type.contains("big.") {
+        imports.push("math/big");
+    }
+    if go_type.contains("time.") {
+        imports.push("time");
+    }
+    imports
+}
+
+/// Returns the imports a Python type needs, as `(module, name)` pairs for `from module import
+/// name`, with an empty name for a plain `import module`.
+fn python_imports_for_type(python_type: &str) -> Vec<(&'static str, &'static str)> {
+    const TYPING_NAMES: &[&str] = &[
+        "Any", "Dict", "List", "Literal", "Optional", "Tuple", "Union",
+    ];
+    let mut imports = Vec::new();
+    for word in python_type.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '.')) {
+        if let Some(name) = TYPING_NAMES.iter().find(|name| **name == word) {
+            imports.push(("typing", *name));
+        } else if word.starts_with("datetime.") {
+            imports.push(("datetime", ""));
+        } else if word.starts_with("uuid.") {
+            imports.push(("uuid", ""));
+        } else if word == "Decimal" {
+            imports.push(("decimal", "Decimal"));
+        }
+    }
+    imports
+}
+
+fn rust_type_to_java_type(ty: &Type) -> String {
+    rust_type_to_java_type_with_collection(ty, "List")
+}
+
+/// Maps a type to Java, declaring `Vec<T>` as the given collection type, e.g. `Stream<T>`.
+fn rust_type_to_java_type_with_collection(ty: &Type, collection: &str) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" => "byte",
+                "u8" => "short",
+                "i16" => "short",
+                "u16" => "int",
+                "i32" => "int",
+                "u32" => "long",
+                "i64" => "long",
+                "u64" => "BigInteger",
+                "isize" | "usize" => "long",
+                "i128" => "BigInteger",
+                "u128" => "BigInteger",
+                "f16" | "bf16" | "f32" => "float",
+                "f64" => "double",
+                "bool" => "boolean",
+                "String" | "ArrayString" | "Tz" => "String",
+                "char" => "char",
+                "BigInt" | "BigUint" => "BigInteger",
+                "BigDecimal" => "BigDecimal",
+                "SystemTime" => "Instant",
+                "DateTime" | "OffsetDateTime" => "OffsetDateTime",
+                "NaiveDateTime" => "LocalDateTime",
+                "NaiveDate" => "LocalDate",
+                "Uuid" => "UUID",
+                "ByteBuf" => {
+                    return rust_type_to_java_type_with_collection(&byte_vec_type(), collection)
+                }
+                "StatusCode" if is_http_type(type_path) => "int",
+                "Method" if is_http_type(type_path) => "String",
+                "HeaderMap" if is_http_type(type_path) => "Map<String, String>",
+                _ if uom_quantity_unit(type_path).is_some() => "double",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return convert_java_primitive_type_to_wrapper_class(
+                            rust_type_to_java_type_with_collection(
+                                strip_nested_options(inner_type),
+                                collection,
+                            )
+                            .as_str(),
+                        );
+                    }
+                    // A bare `Option`, e.g. behind a type alias hiding its argument, could
+                    // hold anything.
+                    "Object"
+                }
+                // An object keyed by `Ok` or `Err`, which the field's comment spells out.
+                "Result" => "Map<String, Object>",
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "Map<{}, {}>",
+                            convert_java_primitive_type_to_wrapper_class(
+                                rust_type_to_java_type_with_collection(key_type, collection)
+                                    .as_str()
+                            ),
+                            convert_java_primitive_type_to_wrapper_class(
+                                rust_type_to_java_type_with_collection(value_type, collection)
+                                    .as_str()
+                            )
+                        );
+                    }
+                    "Map<Object, Object>"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_java_type_with_collection(inner_type, collection);
+                    }
+                    "Object"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!(
+                            "{}<{}>",
+                            collection,
+                            convert_java_primitive_type_to_wrapper_class(
+                                rust_type_to_java_type_with_collection(inner_type, collection)
+                                    .as_str()
+                            )
+                        );
+                    }
+                    return format!("{}<Object>", collection);
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => {
+            let inner_type = rust_type_to_java_type_with_collection(&array.elem, collection);
+            format!("{}[]", &inner_type)
+        }
+        Type::Slice(slice) => format!(
+            "{}[]",
+            rust_type_to_java_type_with_collection(&slice.elem, collection)
+        ),
+        Type::Reference(reference) => {
+            rust_type_to_java_type_with_collection(&reference.elem, collection)
+        }
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "Void".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple
+                .elems
+                .iter()
+                .map(|elem| {
+                    convert_java_primitive_type_to_wrapper_class(
+                        rust_type_to_java_type_with_collection(elem, collection).as_str(),
+                    )
+                })
+                .collect();
+            format!("Tuple<{}>", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Exception".to_string(),
+        _ => "Object".to_string(), // Fallback to 'Object' for unsupported or complex types.
+    }
+}
+
+/// Like `rust_type_to_java_type_with_collection`, declaring an `Option<T>` field as
+/// `Optional<T>`. Options nested in collections stay nullable, as `Optional` isn't meant to be
+/// stored in them.
+fn rust_type_to_java_optional_type(ty: &Type, collection: &str) -> String {
+    if let Type::Path(type_path) = ty {
+        if type_path.path.segments.last().unwrap().ident == "Option" {
+            if let Some(inner_type) = first_type_argument(type_path) {
+                return format!(
+                    "Optional<{}>",
+                    convert_java_primitive_type_to_wrapper_class(
+                        &rust_type_to_java_type_with_collection(
+                            strip_nested_options(inner_type),
+                            collection
+                        )
+                    )
+                );
+            }
+        }
+    }
+    rust_type_to_java_type_with_collection(ty, collection)
+}
+
+fn convert_java_primitive_type_to_wrapper_class(inner_type: &str) -> String {
+    match inner_type {
+        "byte" => "Byte",
+        "short" => "Short",
+        "int" => "Integer",
+        "long" => "Long",
+        "float" => "Float",
+        "double" => "Double",
+        "char" => "Character",
+        "boolean" => "Boolean",
+        _ => inner_type,
+    }
+    .to_string()
+}
+
+fn rust_type_to_csharp_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" => "sbyte",
+                "u8" => "byte",
+                "i16" => "short",
+                "u16" => "ushort",
+                "i32" => "int",
+                "u32" => "uint",
+                "i64" => "long",
+                "u64" => "ulong",
+                "isize" => "nint",
+                "usize" => "nuint",
+                "i128" => "BigInteger",
+                "u128" => "BigInteger",
+                "f16" | "bf16" | "f32" => "float",
+                "f64" => "double",
+                "bool" => "bool",
+                "String" | "ArrayString" | "Tz" => "string",
+                "char" => "char",
+                "BigInt" | "BigUint" => "BigInteger",
+                "BigDecimal" => "decimal",
+                "DateTime" | "OffsetDateTime" | "SystemTime" => "DateTimeOffset",
+                "NaiveDateTime" => "DateTime",
+                "NaiveDate" => "DateOnly",
+                "Uuid" => "Guid",
+                "ByteBuf" => return rust_type_to_csharp_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "int",
+                "Method" if is_http_type(type_path) => "string",
+                "HeaderMap" if is_http_type(type_path) => "Dictionary<string, string>",
+                _ if uom_quantity_unit(type_path).is_some() => "double",
+                // `T?` for value and reference types alike, as with nullable reference types enabled.
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "{}?",
+                            rust_type_to_csharp_type(strip_nested_options(inner_type))
+                        );
+                    }
+                    "Object"
+                }
+                // An object keyed by `Ok` or `Err`, which the field's comment spells out.
+                "Result" => "Dictionary<string, object>",
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "Dictionary<{}, {}>",
+                            rust_type_to_csharp_type(key_type),
+                            rust_type_to_csharp_type(value_type)
+                        );
+                    }
+                    "Dictionary<Object, Object>"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_csharp_type(inner_type);
+                    }
+                    "Object"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("List<{}>", rust_type_to_csharp_type(inner_type));
+                    }
+                    "List<Object>"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => {
+            let inner_type = rust_type_to_csharp_type(&array.elem);
+            format!("{}[]", &inner_type)
+        }
+        Type::Slice(slice) => format!("{}[]", rust_type_to_csharp_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_csharp_type(&reference.elem),
+        // The unit type serializes as null. C# has no type for it, and `void` can't type a field.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "object".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_csharp_type).collect();
+            format!("({})", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Exception".to_string(),
+        _ => "Object".to_string(), // Fallback to 'Object' for unsupported or complex types.
+    }
+}
+
+fn rust_type_to_kotlin_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" => "Byte",
+                "u8" => "UByte",
+                "i16" => "Short",
+                "u16" => "UShort",
+                "i32" => "Int",
+                "u32" => "UInt",
+                "i64" => "Long",
+                "u64" => "ULong",
+                "isize" => "Long",
+                "usize" => "ULong",
+                "i128" => "BigInteger",
+                "u128" => "BigInteger",
+                "f16" | "bf16" | "f32" => "Float",
+                "f64" => "Double",
+                "bool" => "Boolean",
+                "String" | "ArrayString" | "Tz" => "String",
+                "char" => "Char",
+                "BigInt" | "BigUint" => "BigInteger",
+                "BigDecimal" => "BigDecimal",
+                "SystemTime" => "Instant",
+                "DateTime" | "OffsetDateTime" => "OffsetDateTime",
+                "NaiveDateTime" => "LocalDateTime",
+                "NaiveDate" => "LocalDate",
+                "Uuid" => "UUID",
+                "ByteBuf" => return rust_type_to_kotlin_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "Int",
+                "Method" if is_http_type(type_path) => "String",
+                "HeaderMap" if is_http_type(type_path) => "Map<String, String>",
+                _ if uom_quantity_unit(type_path).is_some() => "Double",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "{}?",
+                            rust_type_to_kotlin_type(strip_nested_options(inner_type))
+                        );
+                    }
+                    "Any?"
+                }
+                // An object keyed by `Ok` or `Err`, which the field's comment spells out.
+                "Result" => "Map<String, Any>",
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "Map<{}, {}>",
+                            rust_type_to_kotlin_type(key_type),
+                            rust_type_to_kotlin_type(value_type)
+                        );
+                    }
+                    "Map<Any, Any>"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_kotlin_type(inner_type);
+                    }
+                    "Any"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("List<{}>", rust_type_to_kotlin_type(inner_type));
+                    }
+                    "List<Any>"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => {
+            let inner_type = rust_type_to_kotlin_type(&array.elem);
+            format!("Array<{}>", inner_type)
+        }
+        Type::Slice(slice) => format!("Array<{}>", rust_type_to_kotlin_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_kotlin_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "Nothing?".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_kotlin_type).collect();
+            match types.len() {
+                2 => format!("Pair<{}>", types.join(", ")),
+                3 => format!("Triple<{}>", types.join(", ")),
+                _ => "List<Any>".to_string(),
+            }
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Exception".to_string(),
+        _ => "Any".to_string(), // Fallback to 'Any' for unsupported or complex types.
+    }
+}
+
+fn rust_type_to_swift_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" => "Int8",
+                "u8" => "UInt8",
+                "i16" => "Int16",
+                "u16" => "UInt16",
+                "i32" => "Int32",
+                "u32" => "UInt32",
+                "i64" => "Int64",
+                "u64" => "UInt64",
+                "isize" => "Int",
+                "usize" => "UInt",
+                "i128" => "Int128",
+                "u128" => "UInt128",
+                "f16" | "bf16" | "f32" => "Float",
+                "f64" => "Double",
+                "bool" => "Bool",
+                "String" | "ArrayString" | "Tz" => "String",
+                "char" => "Character",
+                "BigDecimal" => "Decimal",
+                "DateTime" | "NaiveDateTime" | "NaiveDate" | "OffsetDateTime" | "SystemTime" => {
+                    "Date"
+                }
+                "Uuid" => "UUID",
+                "ByteBuf" => return rust_type_to_swift_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "Int",
+                "Method" if is_http_type(type_path) => "String",
+                "HeaderMap" if is_http_type(type_path) => "[String: String]",
+                _ if uom_quantity_unit(type_path).is_some() => "Double",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "{}?",
+                            rust_type_to_swift_type(strip_nested_options(inner_type))
+                        );
+                    }
+                    "Any?"
+                }
+                // An object keyed by `Ok` or `Err`, which the field's comment spells out.
+                "Result" => "[String: Any]",
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "[{}: {}]",
+                            rust_type_to_swift_type(key_type),
+                            rust_type_to_swift_type(value_type)
+                        );
+                    }
+                    "[AnyHashable: Any]"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_swift_type(inner_type);
+                    }
+                    "Any"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("[{}]", rust_type_to_swift_type(inner_type));
+                    }
+                    "[Any]"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => {
+            let inner_type = rust_type_to_swift_type(&array.elem);
+            format!("[{}]", inner_type)
+        }
+        Type::Slice(slice) => format!("[{}]", rust_type_to_swift_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_swift_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "Never?".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_swift_type).collect();
+            format!("({})", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "Error".to_string(),
+        _ => "Any".to_string(), // Fallback to 'Any' for unsupported or complex types.
+    }
+}
+
+fn rust_type_to_cpp_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => &last_segment,
+                "i8" => "int8_t",
+                "u8" => "uint8_t",
+                "i16" => "int16_t",
+                "u16" => "uint16_t",
+                "i32" => "int32_t",
+                "u32" => "uint32_t",
+                "i64" => "int64_t",
+                "u64" => "uint64_t",
+                "isize" => "std::ptrdiff_t",
+                "usize" => "std::size_t",
+                "i128" => "__int128",
+                "u128" => "unsigned __int128",
+                "f16" | "bf16" | "f32" => "float",
+                "f64" => "double",
+                "bool" => "bool",
+                "String" | "ArrayString" | "Tz" | "str" => "std::string",
+                "char" => "char32_t",
+                "BigInt" | "BigUint" | "BigDecimal" => "std::string",
+                "SystemTime" => "std::chrono::system_clock::time_point",
+                "DateTime" | "NaiveDateTime" | "NaiveDate" | "OffsetDateTime" => "std::string",
+                "Uuid" => "std::string",
+                "ByteBuf" => return rust_type_to_cpp_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "int",
+                "Method" if is_http_type(type_path) => "std::string",
+                "HeaderMap" if is_http_type(type_path) => "std::map<std::string, std::string>",
+                _ if uom_quantity_unit(type_path).is_some() => "double",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return format!(
+                            "std::optional<{}>",
+                            rust_type_to_cpp_type(strip_nested_options(inner_type))
+                        );
+                    }
+                    "std::optional<std::any>"
+                }
+                // An object keyed by `Ok` or `Err`, which the field's comment spells out.
+                "Result" => "std::map<std::string, std::any>",
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((key_type, value_type)) = map_key_value_types(type_path) {
+                        return format!(
+                            "std::map<{}, {}>",
+                            rust_type_to_cpp_type(key_type),
+                            rust_type_to_cpp_type(value_type)
+                        );
+                    }
+                    "std::map<std::any, std::any>"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_cpp_type(inner_type);
+                    }
+                    "std::any"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("std::vector<{}>", rust_type_to_cpp_type(inner_type));
+                    }
+                    "std::vector<std::any>"
+                }
+                _ => &last_segment, // Fallback to the actual Rust type name, assuming it's a custom type or enum.
+            }
+            .to_string()
+        }
+        Type::Array(array) => {
+            let inner_type = rust_type_to_cpp_type(&array.elem);
+            let len = &array.len;
+            format!("std::array<{}, {}>", inner_type, quote!(#len))
+        }
+        Type::Slice(slice) => format!("std::vector<{}>", rust_type_to_cpp_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_cpp_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "std::nullptr_t".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_cpp_type).collect();
+            format!("std::tuple<{}>", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "std::exception_ptr".to_string(),
+        _ => "std::any".to_string(), // Fallback to 'std::any' for unsupported or complex types.
+    }
+}
+
+/// Returns the type of a field that becomes a table of its own in a TOML example, i.e. a custom
+/// type, possibly optional or behind a smart pointer. Type parameters aren't known to derive
+/// `StructToString`, so they're left out.
+fn toml_table_type<'a>(ty: &'a Type, type_params: &[String]) -> Option<&'a Type> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let name = type_path.path.segments.last().unwrap().ident.to_string();
+    match name.as_str() {
+        "Option" | "Box" | "Rc" | "Arc" => {
+            toml_table_type(first_type_argument(type_path)?, type_params)
+        }
+        _ if is_builtin_type(type_path) || type_params.contains(&name) => None,
+        _ => Some(ty),
+    }
+}
+
+/// Returns a placeholder TOML value for a type, e.g. `0` or `""`. Collections are empty, and
+/// custom types nested in them are left out with them.
+fn rust_type_to_toml_value(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                "i8" | "i16" | "i32" | "i64" | "i128" | "isize" => "0",
+                "u8" | "u16" | "u32" | "u64" | "u128" | "usize" => "0",
+                "f16" | "bf16" | "f32" | "f64" => "0.0",
+                _ if uom_quantity_unit(type_path).is_some() => "0.0",
+                "bool" => "false",
+                "StatusCode" if is_http_type(type_path) => "200",
+                "DateTime" | "OffsetDateTime" | "SystemTime" => "1970-01-01T00:00:00Z",
+                "NaiveDateTime" => "1970-01-01T00:00:00",
+                "NaiveDate" => "1970-01-01",
+                "Option" | "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_toml_value(inner_type);
+                    }
+                    "\"\""
+                }
+                "Vec" | "TinyVec" | "SmallVec" | "ByteBuf" => "[]",
+                "HashMap" | "BTreeMap" | "IndexMap" | "Result" => "{}",
+                "HeaderMap" if is_http_type(type_path) => "{}",
+                _ => "\"\"",
+            }
+            .to_string()
+        }
+        Type::Array(_) | Type::Slice(_) | Type::Tuple(_) => "[]".to_string(),
+        Type::Reference(reference) => rust_type_to_toml_value(&reference.elem),
+        _ => "\"\"".to_string(),
+    }
+}
+
+/// Maps a type to a Terraform type constraint. Terraform has no named types, so custom types
+/// become `any`.
+fn rust_type_to_hcl_type(ty: &Type) -> String {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => "any",
+                "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "i128" | "u128"
+                | "isize" | "usize" => "number",
+                "f16" | "bf16" | "f32" | "f64" => "number",
+                "bool" => "bool",
+                "String" | "ArrayString" | "Tz" | "str" | "char" => "string",
+                "BigInt" | "BigUint" => "number",
+                "BigDecimal" => "string",
+                "DateTime" | "NaiveDateTime" | "NaiveDate" | "OffsetDateTime" | "SystemTime" => {
+                    "string"
+                }
+                "Uuid" => "string",
+                "ByteBuf" => return rust_type_to_hcl_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => "number",
+                "Method" if is_http_type(type_path) => "string",
+                "HeaderMap" if is_http_type(type_path) => "map(string)",
+                _ if uom_quantity_unit(type_path).is_some() => "number",
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_hcl_type(strip_nested_options(inner_type));
+                    }
+                    "any"
+                }
+                // Terraform map keys are always strings.
+                // serde writes a `Result` as an object keyed by `Ok` or `Err`.
+                "Result" => "map(any)",
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((_, value_type)) = map_key_value_types(type_path) {
+                        return format!("map({})", rust_type_to_hcl_type(value_type));
+                    }
+                    "map(any)"
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_hcl_type(inner_type);
+                    }
+                    "any"
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return format!("list({})", rust_type_to_hcl_type(inner_type));
+                    }
+                    "list(any)"
+                }
+                _ => "any",
+            }
+            .to_string()
+        }
+        Type::Array(array) => format!("list({})", rust_type_to_hcl_type(&array.elem)),
+        Type::Slice(slice) => format!("list({})", rust_type_to_hcl_type(&slice.elem)),
+        Type::Reference(reference) => rust_type_to_hcl_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => "any".to_string(),
+        Type::Tuple(tuple) => {
+            let types: Vec<String> = tuple.elems.iter().map(rust_type_to_hcl_type).collect();
+            format!("tuple([{}])", types.join(", "))
+        }
+        Type::TraitObject(_) if is_dyn_error(ty) => "string".to_string(),
+        _ => "any".to_string(),
+    }
+}
+
+/// Maps a type to the SQL-like name of a CSV column holding it, or `None` when the type
+/// doesn't fit in a single column.
+fn rust_type_to_csv_type(ty: &Type) -> Option<&'static str> {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            let csv_type = match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => return None,
+                "i8" | "u8" | "i16" => "SMALLINT",
+                "u16" | "i32" => "INTEGER",
+                "u32" | "i64" | "isize" => "BIGINT",
+                "u64" | "usize" | "i128" | "u128" | "BigInt" | "BigUint" | "BigDecimal" => {
+                    "NUMERIC"
+                }
+                "f16" | "bf16" | "f32" => "REAL",
+                "f64" => "DOUBLE PRECISION",
+                "bool" => "BOOLEAN",
+                "char" => "CHAR(1)",
+                "String" | "ArrayString" | "Tz" | "str" => "TEXT",
+                "Uuid" => "UUID",
+                "ByteBuf" => "BYTEA",
+                "DateTime" | "OffsetDateTime" | "SystemTime" => "TIMESTAMP WITH TIME ZONE",
+                "NaiveDateTime" => "TIMESTAMP",
+                "NaiveDate" => "DATE",
+                _ if uom_quantity_unit(type_path).is_some() => "DOUBLE PRECISION",
+                "Box" | "Rc" | "Arc" => {
+                    return first_type_argument(type_path).and_then(rust_type_to_csv_type)
+                }
+                _ => return None,
+            };
+            Some(csv_type)
+        }
+        Type::Reference(reference) => rust_type_to_csv_type(&reference.elem),
+        Type::TraitObject(_) if is_dyn_error(ty) => Some("TEXT"),
+        _ => None,
+    }
+}
+
+/// Returns the YAML lines of an OpenAPI 3 schema describing the type. Nested
+/// schemas (such as array `items`) are indented by two spaces.
+fn rust_type_to_openapi_type(ty: &Type) -> Vec<String> {
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            let lines: &[&str] = match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => {
+                    return vec![format!("$ref: '#/components/schemas/{}'", last_segment)]
+                }
+                "i8" | "u8" | "i16" | "u16" | "i32" => &["type: integer", "format: int32"],
+                "u32" | "i64" | "isize" => &["type: integer", "format: int64"],
+                "u64" | "usize" | "i128" | "u128" | "BigInt" | "BigUint" => &["type: integer"],
+                "f16" | "bf16" | "f32" => &["type: number", "format: float"],
+                "f64" => &["type: number", "format: double"],
+                "BigDecimal" => &["type: string"],
+                "DateTime" | "NaiveDateTime" | "OffsetDateTime" | "SystemTime" => {
+                    &["type: string", "format: date-time"]
+                }
+                "NaiveDate" => &["type: string", "format: date"],
+                "Uuid" => &["type: string", "format: uuid"],
+                "ByteBuf" => return rust_type_to_openapi_type(&byte_vec_type()),
+                "StatusCode" if is_http_type(type_path) => &["type: integer", "format: int32"],
+                "Method" if is_http_type(type_path) => &["type: string"],
+                "HeaderMap" if is_http_type(type_path) => {
+                    &["type: object", "additionalProperties:", "  type: string"]
+                }
+                _ if uom_quantity_unit(type_path).is_some() => &["type: number", "format: double"],
+                "bool" => &["type: boolean"],
+                "String" | "ArrayString" | "Tz" | "char" => &["type: string"],
+                "Option" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_openapi_type(strip_nested_options(inner_type));
+                    }
+                    &["type: object"]
+                }
+                // serde writes a `Result` as an object keyed by `Ok` or `Err`.
+                "Result" => &["type: object"],
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    if let Some((_, value_type)) = map_key_value_types(type_path) {
+                        let mut lines = vec![
+                            "type: object".to_string(),
+                            "additionalProperties:".to_string(),
+                        ];
+                        for line in rust_type_to_openapi_type(value_type) {
+                            lines.push(format!("  {}", line));
+                        }
+                        return lines;
+                    }
+                    &["type: object"]
+                }
+                // Smart pointers are transparent in the serialized shape.
+                "Box" | "Rc" | "Arc" => {
+                    if let Some(inner_type) = first_type_argument(type_path) {
+                        return rust_type_to_openapi_type(inner_type);
+                    }
+                    &["type: object"]
+                }
+                "Vec" | "TinyVec" | "SmallVec" => {
+                    if let Some(inner_type) = vec_element_type(type_path) {
+                        return openapi_array_type(inner_type);
+                    }
+                    &["type: array"]
+                }
+                // Fallback to a reference, assuming it's a custom type or enum with its own schema.
+                _ => return vec![format!("$ref: '#/components/schemas/{}'", last_segment)],
+            };
+            lines.iter().map(|line| line.to_string()).collect()
+        }
+        Type::Array(array) => openapi_array_type(&array.elem),
+        Type::Slice(slice) => openapi_array_type(&slice.elem),
+        Type::Reference(reference) => rust_type_to_openapi_type(&reference.elem),
+        // The unit type serializes as null.
+        Type::Tuple(tuple) if tuple.elems.is_empty() => {
+            vec!["nullable: true".to_string(), "enum: [null]".to_string()]
+        }
+        Type::Tuple(_) => vec!["type: array".to_string()],
+        Type::TraitObject(_) if is_dyn_error(ty) => vec!["type: string".to_string()],
+        _ => vec!["type: object".to_string()], // Fallback to 'object' for unsupported or complex types.
+    }
+}
+
+/// Returns the keywords of a JSON Schema describing the type, as pairs of a key and its
+/// rendered JSON value. Strings get a `format` where their type tells which.
+fn rust_type_to_json_schema(ty: &Type) -> Vec<(String, String)> {
+    let keywords = |pairs: &[(&str, &str)]| {
+        pairs
+            .iter()
+            .map(|(key, value)| (key.to_string(), json_string(value)))
+            .collect::<Vec<_>>()
+    };
+    match ty {
+        Type::Path(type_path) => {
+            let last_segment = type_path.path.segments.last().unwrap().ident.to_string();
+            match last_segment.as_str() {
+                feature_type if is_unmapped_feature_type(feature_type) => {
+                    keywords(&[("$ref", &format!("#/$defs/{}", last_segment))])
+                }
+                "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "i128" | "u128"
+                | "isize" | "usize" | "BigInt" | "BigUint" => keywords(&[("type", "integer")]),
+                "StatusCode" if is_http_type(type_path) => keywords(&[("type", "integer")]),
+                "f16" | "bf16" | "f32" | "f64" => keywords(&[("type", "number")]),
+                _ if uom_quantity_unit(type_path).is_some() => keywords(&[("type", "number")]),
+                "bool" => keywords(&[("type", "boolean")]),
+                // The capacity of a fixed-capacity string bounds its length.
+                "ArrayString" => {
+                    let mut schema = keywords(&[("type", "string")]);
+                    if let Some(capacity) = const_type_argument(type_path) {
+                        schema.push(("maxLength".to_string(), capacity));
+                    }
+                    schema
+                }
+                "String" | "Tz" | "str" | "char" | "BigDecimal" => keywords(&[("type", "string")]),
+                "Method" if is_http_type(type_path) => keywords(&[("type", "string")]),
+                "DateTime" | "NaiveDateTime" | "OffsetDateTime" | "SystemTime" => {
+                    keywords(&[("type", "string"), ("format", "date-time")])
+                }
+                "NaiveDate" => keywords(&[("type", "string"), ("format", "date")]),
+                "Uuid" => keywords(&[("type", "string"), ("format", "uuid")]),
+                "Url" | "Uri" => keywords(&[("type", "string"), ("format", "uri")]),
+                "Ipv4Addr" => keywords(&[("type", "string"), ("format", "ipv4")]),
+                "Ipv6Addr" => keywords(&[("type", "string"), ("format", "ipv6")]),
+                "ByteBuf" => rust_type_to_json_schema(&byte_vec_type()),
+                "HeaderMap" if is_http_type(type_path) => {
+                    let mut schema = keywords(&[("type", "object")]);
+                    schema.push((
+                        "additionalProperties".to_string(),
+                        json_object(&keywords(&[("type", "string")])),
+                    ));
+                    schema
+                }
+                "Option" | "Box" | "Rc" | "Arc" => match first_type_argument(type_path) {
+                    Some(inner_type) => rust_type_to_json_schema(inner_type),
+                    None => keywords(&[("type", "object")]),
+                },
+                // serde writes a `Result` as an object keyed by `Ok` or `Err`.
+                "Result" => keywords(&[("type", "object")]),
+                "HashMap" | "BTreeMap" | "IndexMap" => {
+                    let mut schema = keywords(&[("type", "object")]);
+                    if let Some((_, value_type)) = map_key_value_types(type_path) {
+                        schema.push((
+                            "additionalProperties".to_string(),
+                            json_object(&rust_type_to_json_schema(value_type)),
+                        ));
+                    }
+                    schema
+                }
+                "Vec" | "TinyVec" | "SmallVec" => match vec_element_type(type_path) {
+                    Some(inner_type) => json_schema_array_type(inner_type),
+                    None => keywords(&[("type", "array")]),
+                },
+                // Fallback to a reference, assuming it's a custom type with its own schema.
+                _ => keywords(&[("$ref", &format!("#/$defs/{}", last_segment))]),
+            }
+        }
+        Type::Array(array) => json_schema_array_type(&array.elem),
+        Type::Slice(slice) => json_schema_array_type(&slice.elem),
+        Type::Reference(reference) => rust_type_to_json_schema(&reference.elem),
+        Type::Tuple(tuple) if !tuple.elems.is_empty() => {
+            let items = tuple
+                .elems
+                .iter()
+                .map(|elem| json_object(&rust_type_to_json_schema(elem)))
+                .collect::<Vec<_>>();
+            vec![
+                ("type".to_string(), json_string("array")),
+                ("prefixItems".to_string(), json_array(&items)),
+                ("items".to_string(), "false".to_string()),
+            ]
+        }
+        Type::Tuple(_) => keywords(&[("type", "null")]),
+        Type::TraitObject(_) if is_dyn_error(ty) => keywords(&[("type", "string")]),
+        _ => keywords(&[("type", "object")]),
+    }
+}
+
+fn json_schema_array_type(inner_type: &Type) -> Vec<(String, String)> {
+    vec![
+        ("type".to_string(), json_string("array")),
+        (
+            "items".to_string(),
+            json_object(&rust_type_to_json_schema(inner_type)),
+        ),
+    ]
+}
+
+/// Renders a JSON object, indented by two spaces, from keys and already rendered values.
+fn json_object(entries: &[(String, String)]) -> String {
+    if entries.is_empty() {
+        return String::from("{}");
+    }
+    let entries = entries
+        .iter()
+        .map(|(key, value)| format!("  {}: {}", json_string(key), value.replace('\n', "\n  ")))
+        .collect::<Vec<_>>();
+    format!("{{\n{}\n}}", entries.join(",\n"))
+}
+
+/// Renders a JSON array, one already rendered element per line.
+fn json_array(elements: &[String]) -> String {
+    let elements = elements
+        .iter()
+        .map(|element| format!("  {}", element.replace('\n', "\n  ")))
+        .collect::<Vec<_>>();
+    format!("[\n{}\n]", elements.join(",\n"))
+}
+
+/// Quotes and escapes a string as a JSON string literal.
+fn json_string(value: &str) -> String {
+    let mut res = String::from("\"");
+    for c in value.chars() {
+        match c {
+            '"' => res.push_str("\\\""),
+            '\\' => res.push_str("\\\\"),
+            '\n' => res.push_str("\\n"),
+            '\r' => res.push_str("\\r"),
+            '\t' => res.push_str("\\t"),
+            c if c.is_control() => res.push_str(&format!("\\u{:04x}", c as u32)),
+            c => res.push(c),
+        }
+    }
+    res.push('"');
+    res
+}
+
+fn openapi_array_type(inner_type: &Type) -> Vec<String> {
+    let mut lines = vec!["type: array".to_string(), "items:".to_string()];
+    for line in rust_type_to_openapi_type(inner_type) {
+        lines.push(format!("  {}", line));
+    }
+    lines
+}
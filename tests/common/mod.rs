@@ -0,0 +1,46 @@
+//! Golden-file assertions shared by the integration tests.
+//!
+//! Each expected output lives in `tests/golden/<name>`. Run the tests with `BLESS=1` to write
+//! the generated outputs there instead of comparing them, e.g. after adding a test or changing
+//! an output on purpose, then review the diff.
+
+use std::path::PathBuf;
+
+/// Compares `generated` with the golden file `tests/golden/<name>`, or overwrites the file with
+/// it when the `BLESS` environment variable is `1`.
+#[track_caller]
+pub fn assert_golden(name: &str, generated: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(name);
+
+    // The files end with a newline, like any other text file, which isn't part of the output.
+    if std::env::var("BLESS").as_deref() == Ok("1") {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(&path, format!("{}\n", generated)).unwrap();
+        return;
+    }
+    let expected = match std::fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(err) => panic!(
+            "can't read the golden file {} ({}), run the tests with BLESS=1 to create it",
+            path.display(),
+            err
+        ),
+    };
+    let expected = expected.strip_suffix('\n').unwrap_or(&expected);
+
+    println!("--- {} --- ", name);
+    println!("--- WHAT WAS GENERATED --- ");
+    println!("{}", generated);
+    println!("--- WHAT WAS EXPECTED --- ");
+    println!("{}", expected);
+
+    assert_eq!(
+        generated,
+        expected,
+        "the output differs from {}, run the tests with BLESS=1 to update it",
+        path.display()
+    );
+}
@@ -0,0 +1,5 @@
+type BigNumberTestStruct struct {
+    Signed big.Int `json:"signed"`
+    Unsigned big.Int `json:"unsigned"`
+    Amount big.Float `json:"amount"`
+}
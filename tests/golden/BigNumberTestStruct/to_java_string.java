@@ -0,0 +1,5 @@
+public class BigNumberTestStruct {
+    public BigInteger signed;
+    public BigInteger unsigned;
+    public BigDecimal amount;
+}
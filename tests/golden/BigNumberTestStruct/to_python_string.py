@@ -0,0 +1,7 @@
+@dataclass_json
+@dataclass
+class BigNumberTestStruct:
+    signed: int
+    unsigned: int
+    amount: Decimal
+
@@ -0,0 +1,5 @@
+interface BigNumberTestStruct {
+    signed: bigint;
+    unsigned: bigint;
+    amount: string;
+}
@@ -0,0 +1,5 @@
+type ByteBufTestStruct struct {
+    Payload []uint8 `json:"payload"`
+    Signature *[]uint8 `json:"signature,omitempty"`
+    Raw []uint8 `json:"raw"`
+}
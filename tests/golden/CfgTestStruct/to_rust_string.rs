@@ -0,0 +1,7 @@
+struct CfgTestStruct {
+    always: i32,
+    #[cfg(test)]
+    test_only: i32,
+    #[cfg(all(test, not(target_os = "none")))]
+    nested_cfg: String
+}
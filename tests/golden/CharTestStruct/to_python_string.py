@@ -0,0 +1,7 @@
+@dataclass_json
+@dataclass
+class CharTestStruct:
+    initial: str  # single character
+    letters: List[str]  # single character
+    middle_initial: Optional[str]  # single character
+
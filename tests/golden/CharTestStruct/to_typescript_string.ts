@@ -0,0 +1,5 @@
+interface CharTestStruct {
+    initial: string; // single character
+    letters: string[]; // single character
+    middle_initial?: string | null; // single character
+}
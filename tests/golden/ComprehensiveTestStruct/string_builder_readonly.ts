@@ -0,0 +1,15 @@
+interface ComprehensiveTestStruct {
+    readonly int_field: number;
+    readonly uint_field: number;
+    readonly float_field: number;
+    readonly bool_field: boolean;
+    readonly char_field: string; // single character
+    readonly str_field: string;
+    readonly option_field?: number | null;
+    readonly array_field: number[];
+    readonly slice_field: number[];
+    readonly tuple_field: [number, string];
+    readonly tuple_struct_field: TupleStruct;
+    readonly enum_field: AnEnum;
+    readonly nested_struct_field: NestedStruct;
+}
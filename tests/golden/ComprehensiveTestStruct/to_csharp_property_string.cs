@@ -0,0 +1,15 @@
+public class ComprehensiveTestStruct {
+    public int IntField { get; set; }
+    public uint UintField { get; set; }
+    public double FloatField { get; set; }
+    public bool BoolField { get; set; }
+    public char CharField { get; set; }
+    public string StrField { get; set; }
+    public int? OptionField { get; set; }
+    public int[] ArrayField { get; set; }
+    public List<int> SliceField { get; set; }
+    public (int, string) TupleField { get; set; }
+    public TupleStruct TupleStructField { get; set; }
+    public AnEnum EnumField { get; set; }
+    public NestedStruct NestedStructField { get; set; }
+}
@@ -0,0 +1 @@
+public record ComprehensiveTestStruct(int IntField, uint UintField, double FloatField, bool BoolField, char CharField, string StrField, int? OptionField, int[] ArrayField, List<int> SliceField, (int, string) TupleField, TupleStruct TupleStructField, AnEnum EnumField, NestedStruct NestedStructField);
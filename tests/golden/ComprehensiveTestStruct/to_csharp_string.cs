@@ -0,0 +1,15 @@
+public class ComprehensiveTestStruct {
+    public int int_field;
+    public uint uint_field;
+    public double float_field;
+    public bool bool_field;
+    public char char_field;
+    public string str_field;
+    public int? option_field;
+    public int[] array_field;
+    public List<int> slice_field;
+    public (int, string) tuple_field;
+    public TupleStruct tuple_struct_field;
+    public AnEnum enum_field;
+    public NestedStruct nested_struct_field;
+}
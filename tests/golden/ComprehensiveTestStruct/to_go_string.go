@@ -0,0 +1,15 @@
+type ComprehensiveTestStruct struct {
+    IntField int32 `json:"int_field"`
+    UintField uint32 `json:"uint_field"`
+    FloatField float64 `json:"float_field"`
+    BoolField bool `json:"bool_field"`
+    CharField rune `json:"char_field"`
+    StrField string `json:"str_field"`
+    OptionField *int32 `json:"option_field,omitempty"`
+    ArrayField [3]int32 `json:"array_field"`
+    SliceField []int32 `json:"slice_field"`
+    TupleField struct{} `json:"tuple_field"` // CANNOT CONVERT THIS TO THE GO PROGRAMMING LANGUAGE. TUPLES ARE UNSUPPORTED BY GO: (int32, string)
+    TupleStructField TupleStruct `json:"tuple_struct_field"`
+    EnumField AnEnum `json:"enum_field"`
+    NestedStructField NestedStruct `json:"nested_struct_field"`
+}
@@ -0,0 +1,15 @@
+const ComprehensiveTestStructResolvers = {
+    int_field: (parent: ComprehensiveTestStruct) => parent.int_field,
+    uint_field: (parent: ComprehensiveTestStruct) => parent.uint_field,
+    float_field: (parent: ComprehensiveTestStruct) => parent.float_field,
+    bool_field: (parent: ComprehensiveTestStruct) => parent.bool_field,
+    char_field: (parent: ComprehensiveTestStruct) => parent.char_field,
+    str_field: (parent: ComprehensiveTestStruct) => parent.str_field,
+    option_field: (parent: ComprehensiveTestStruct) => parent.option_field,
+    array_field: (parent: ComprehensiveTestStruct) => parent.array_field,
+    slice_field: (parent: ComprehensiveTestStruct) => parent.slice_field,
+    tuple_field: (parent: ComprehensiveTestStruct) => parent.tuple_field,
+    tuple_struct_field: (parent: ComprehensiveTestStruct) => parent.tuple_struct_field,
+    enum_field: (parent: ComprehensiveTestStruct) => parent.enum_field,
+    nested_struct_field: (parent: ComprehensiveTestStruct) => parent.nested_struct_field,
+};
@@ -0,0 +1 @@
+public record ComprehensiveTestStruct(int int_field, long uint_field, double float_field, boolean bool_field, char char_field, String str_field, Integer option_field, int[] array_field, List<Integer> slice_field, Tuple<Integer, String> tuple_field, TupleStruct tuple_struct_field, AnEnum enum_field, NestedStruct nested_struct_field) {}
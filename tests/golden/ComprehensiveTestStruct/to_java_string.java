@@ -0,0 +1,15 @@
+public class ComprehensiveTestStruct {
+    public int int_field;
+    public long uint_field;
+    public double float_field;
+    public boolean bool_field;
+    public char char_field;
+    public String str_field;
+    public Integer option_field;
+    public int[] array_field;
+    public List<Integer> slice_field;
+    public Tuple<Integer, String> tuple_field;
+    public TupleStruct tuple_struct_field;
+    public AnEnum enum_field;
+    public NestedStruct nested_struct_field;
+}
@@ -0,0 +1,15 @@
+data class ComprehensiveTestStruct(
+    val int_field: Int,
+    val uint_field: UInt,
+    val float_field: Double,
+    val bool_field: Boolean,
+    val char_field: Char,
+    val str_field: String,
+    val option_field: Int?,
+    val array_field: Array<Int>,
+    val slice_field: List<Int>,
+    val tuple_field: Pair<Int, String>,
+    val tuple_struct_field: TupleStruct,
+    val enum_field: AnEnum,
+    val nested_struct_field: NestedStruct,
+)
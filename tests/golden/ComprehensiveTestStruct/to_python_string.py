@@ -0,0 +1,17 @@
+@dataclass_json
+@dataclass
+class ComprehensiveTestStruct:
+    int_field: int
+    uint_field: int
+    float_field: float
+    bool_field: bool
+    char_field: str  # single character
+    str_field: str
+    option_field: Optional[int]
+    array_field: List[int]
+    slice_field: List[int]
+    tuple_field: Tuple[int, str]
+    tuple_struct_field: TupleStruct
+    enum_field: AnEnum
+    nested_struct_field: NestedStruct
+
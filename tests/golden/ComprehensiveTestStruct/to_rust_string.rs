@@ -0,0 +1,15 @@
+struct ComprehensiveTestStruct {
+    int_field: i32,
+    uint_field: u32,
+    float_field: f64,
+    bool_field: bool,
+    char_field: char,
+    str_field: String,
+    option_field: Option<i32>,
+    array_field: [i32;3],
+    slice_field: Vec<i32>,
+    tuple_field: (i32,String),
+    tuple_struct_field: TupleStruct,
+    enum_field: AnEnum,
+    nested_struct_field: NestedStruct
+}
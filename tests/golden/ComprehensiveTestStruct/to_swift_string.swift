@@ -0,0 +1,15 @@
+struct ComprehensiveTestStruct {
+    let int_field: Int32
+    let uint_field: UInt32
+    let float_field: Double
+    let bool_field: Bool
+    let char_field: Character
+    let str_field: String
+    let option_field: Int32?
+    let array_field: [Int32]
+    let slice_field: [Int32]
+    let tuple_field: (Int32, String)
+    let tuple_struct_field: TupleStruct
+    let enum_field: AnEnum
+    let nested_struct_field: NestedStruct
+}
@@ -0,0 +1,15 @@
+interface ComprehensiveTestStruct {
+    int_field: number;
+    uint_field: number;
+    float_field: number;
+    bool_field: boolean;
+    char_field: string; // single character
+    str_field: string;
+    option_field?: number | null;
+    array_field: number[];
+    slice_field: number[];
+    tuple_field: [number, string];
+    tuple_struct_field: TupleStruct;
+    enum_field: AnEnum;
+    nested_struct_field: NestedStruct;
+}
@@ -0,0 +1,15 @@
+export const ComprehensiveTestStruct = z.object({
+  int_field: z.number(),
+  uint_field: z.number(),
+  float_field: z.number(),
+  bool_field: z.boolean(),
+  char_field: z.string().length(1),
+  str_field: z.string(),
+  option_field: z.number().nullable().optional(),
+  array_field: z.array(z.number()),
+  slice_field: z.array(z.number()),
+  tuple_field: z.tuple([z.number(), z.string()]),
+  tuple_struct_field: TupleStruct,
+  enum_field: AnEnum,
+  nested_struct_field: NestedStruct,
+});
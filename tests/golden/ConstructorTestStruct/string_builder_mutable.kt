@@ -0,0 +1,5 @@
+data class ConstructorTestStruct(
+    var id: ULong,
+    var name: String,
+    var tags: List<String>,
+)
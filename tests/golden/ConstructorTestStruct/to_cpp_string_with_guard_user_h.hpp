@@ -0,0 +1,10 @@
+#ifndef USER_H
+#define USER_H
+
+struct ConstructorTestStruct {
+    uint64_t id;
+    std::string name;
+    std::vector<std::string> tags;
+};
+
+#endif
@@ -0,0 +1,5 @@
+internal class ConstructorTestStruct {
+    public ulong id;
+    public string name;
+    public List<string> tags;
+}
@@ -0,0 +1,8 @@
+impl std::fmt::Display for ConstructorTestStruct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "id: {:?}", self.id)?;
+        writeln!(f, "name: {:?}", self.name)?;
+        writeln!(f, "tags: {:?}", self.tags)?;
+        Ok(())
+    }
+}
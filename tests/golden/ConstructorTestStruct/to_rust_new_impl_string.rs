@@ -0,0 +1,5 @@
+impl ConstructorTestStruct {
+    pub fn new(id: u64, name: String, tags: Vec<String>) -> Self {
+        Self { id, name, tags }
+    }
+}
@@ -0,0 +1,6 @@
+#[derive(StructToString)]
+struct ConstructorTestStruct {
+    id: u64,
+    name: String,
+    tags: Vec<String>
+}
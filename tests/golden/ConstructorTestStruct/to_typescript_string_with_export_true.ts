@@ -0,0 +1,5 @@
+export interface ConstructorTestStruct {
+    id: number;
+    name: string;
+    tags: string[];
+}
@@ -0,0 +1,5 @@
+type ConstructorTestStruct = {
+    id: number;
+    name: string;
+    tags: string[];
+};
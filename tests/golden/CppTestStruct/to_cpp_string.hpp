@@ -0,0 +1,6 @@
+struct CppTestStruct {
+    uint64_t id;
+    std::map<std::string, uint32_t> scores;
+    std::map<std::string, std::vector<std::optional<double>>> nested;
+    std::vector<std::string> tags;
+};
@@ -0,0 +1,6 @@
+public class DateTypesTestStruct {
+    public OffsetDateTime created_at;
+    public LocalDate birthday;
+    public OffsetDateTime expires_at;
+    public number updated_at;
+}
@@ -0,0 +1,8 @@
+@dataclass_json
+@dataclass
+class DateTypesTestStruct:
+    created_at: datetime.datetime
+    birthday: datetime.date
+    expires_at: datetime.datetime
+    updated_at: float
+
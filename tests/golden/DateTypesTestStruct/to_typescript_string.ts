@@ -0,0 +1,6 @@
+interface DateTypesTestStruct {
+    created_at: string;
+    birthday: string;
+    expires_at: string;
+    updated_at: number;
+}
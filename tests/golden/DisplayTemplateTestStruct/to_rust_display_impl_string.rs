@@ -0,0 +1,5 @@
+impl std::fmt::Display for DisplayTemplateTestStruct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{:03} ({{draft}})", self.code, self.version)
+    }
+}
@@ -0,0 +1,4 @@
+interface ErrorTestStruct {
+    last_error?: Error | null;
+    cause: Error;
+}
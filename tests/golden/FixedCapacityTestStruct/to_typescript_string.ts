@@ -0,0 +1,5 @@
+interface FixedCapacityTestStruct {
+    readings: number[];
+    labels: string[];
+    nested?: number[][] | null;
+}
@@ -0,0 +1,4 @@
+interface FlattenTestStruct extends FlattenBaseStruct, UnregisteredBase {
+    name: string;
+    active: boolean;
+}
@@ -0,0 +1,12 @@
+defmodule FlowTestStruct do
+  defstruct [:id, :name, :nickname, :tags, :scores, :nested]
+
+  @type t :: %__MODULE__{
+    id: integer(),
+    name: String.t(),
+    nickname: String.t() | nil,
+    tags: [String.t()],
+    scores: %{optional(String.t()) => float()},
+    nested: NestedStruct.t() | nil
+  }
+end
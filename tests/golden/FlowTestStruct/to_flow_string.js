@@ -0,0 +1,8 @@
+type FlowTestStruct = {|
+  id: number,
+  name: string,
+  nickname: ?string,
+  tags: Array<string>,
+  scores: { [key: string]: number },
+  nested: ?NestedStruct,
+|};
@@ -0,0 +1,8 @@
+type FlowTestStruct = {
+    Id: int64
+    Name: string
+    Nickname: string option
+    Tags: string list
+    Scores: Map<string, float>
+    Nested: NestedStruct option
+}
@@ -0,0 +1,8 @@
+type flow_test_struct = {
+  id : int;
+  name : string;
+  nickname : string option;
+  tags : string list;
+  scores : (string * float) list;
+  nested : nested_struct option;
+}
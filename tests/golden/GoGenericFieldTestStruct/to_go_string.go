@@ -0,0 +1,8 @@
+type GoGenericFieldTestStruct[T any] struct {
+    Wrapped Wrapper[int32] `json:"wrapped"`
+    Nested *Wrapper[[]string] `json:"nested,omitempty"`
+    Forwarded Wrapper[T] `json:"forwarded"`
+    Borrowed BorrowedWrapper[uint8] `json:"borrowed"`
+    Tags HashSet `json:"tags"`
+    Lock Mutex `json:"lock"`
+}
@@ -0,0 +1,11 @@
+package models
+
+import (
+    "math/big"
+    "time"
+)
+
+type GoMultipleImportsTestStruct struct {
+    DeletedAt *time.Time `json:"deleted_at,omitempty"`
+    Balance big.Int `json:"balance"`
+}
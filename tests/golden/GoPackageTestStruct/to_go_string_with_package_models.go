@@ -0,0 +1,8 @@
+package models
+
+import "time"
+
+type GoPackageTestStruct struct {
+    CreatedAt time.Time `json:"created_at"`
+    Count int32 `json:"count"`
+}
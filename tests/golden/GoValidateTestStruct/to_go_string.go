@@ -0,0 +1,4 @@
+type GoValidateTestStruct struct {
+    Email string `json:"email" validate:"required,email"`
+    Nickname *string `json:"nickname,omitempty"`
+}
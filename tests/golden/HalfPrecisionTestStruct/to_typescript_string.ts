@@ -0,0 +1,4 @@
+interface HalfPrecisionTestStruct {
+    weight: number; // f16 (reduced precision)
+    activations: number[]; // bf16 (reduced precision)
+}
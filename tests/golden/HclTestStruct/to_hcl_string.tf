@@ -0,0 +1,24 @@
+variable "instance_count" {
+  type = number
+}
+
+variable "region" {
+  type = string
+}
+
+variable "zones" {
+  type = list(number)
+}
+
+variable "ports" {
+  type = list(number)
+}
+
+variable "tags" {
+  type = map(string)
+}
+
+variable "description" {
+  type    = string
+  default = null
+}
@@ -0,0 +1,7 @@
+@dataclass_json
+@dataclass
+class HttpTestStruct:
+    status: int
+    method: str
+    headers: Dict[str, str]
+
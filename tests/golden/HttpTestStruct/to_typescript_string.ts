@@ -0,0 +1,5 @@
+interface HttpTestStruct {
+    status: number;
+    method: string;
+    headers: Record<string, string>;
+}
@@ -0,0 +1,9 @@
+interface IntegerWidthTestStruct {
+    delta: number; // i32
+    count: number; // u32
+    id: number; // u64
+    offset?: number | null; // i64
+    samples: number[]; // u64
+    ratio: number;
+    name: string;
+}
@@ -0,0 +1,4 @@
+interface MapTestStruct {
+    color_counts: Record<"Red" | "Green" | "Blue", number>;
+    labels: Record<string, string | null>;
+}
@@ -0,0 +1,9 @@
+@dataclass_json
+@dataclass
+class ModernPythonTestStruct:
+    count: int
+    limit: int | None
+    tags: list[str]
+    scores: dict[str, int]
+    pair: tuple[int, str]
+
@@ -0,0 +1,3 @@
+public class NameAffixTestStructDTO {
+    public BigInteger id;
+}
@@ -0,0 +1,4 @@
+interface NestedGenericsTestStruct {
+    nested: Record<string, number[]>[];
+    deep: Record<string, Record<string, (number | null)[]>>;
+}
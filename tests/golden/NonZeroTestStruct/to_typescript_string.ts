@@ -0,0 +1,5 @@
+interface NonZeroTestStruct {
+    page_size: number; // nonzero
+    retries?: number | null; // nonzero
+    ids: number[]; // nonzero
+}
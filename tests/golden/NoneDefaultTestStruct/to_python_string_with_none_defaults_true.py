@@ -0,0 +1,7 @@
+@dataclass_json
+@dataclass(kw_only=True)
+class NoneDefaultTestStruct:
+    nickname: Optional[str] = None
+    age: Optional[int] = None
+    name: str
+
@@ -0,0 +1,4 @@
+interface PolylineTestStruct {
+    points: Point[];
+    labels?: TupleStruct[] | null;
+}
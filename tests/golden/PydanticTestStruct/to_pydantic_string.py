@@ -0,0 +1,7 @@
+class PydanticTestStruct(BaseModel):
+    id: int
+    name: str
+    nickname: Optional[str] = None
+    tags: list[str]
+    scores: dict[str, float]
+
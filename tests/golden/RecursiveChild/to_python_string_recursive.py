@@ -0,0 +1,9 @@
+@dataclass_json
+@dataclass
+class RecursiveLeaf:
+    value: int
+
+@dataclass_json
+@dataclass
+class RecursiveChild:
+    leaf: Optional[RecursiveLeaf]
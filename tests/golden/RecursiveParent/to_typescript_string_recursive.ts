@@ -0,0 +1,13 @@
+interface RecursiveLeaf {
+    value: number;
+}
+
+interface RecursiveChild {
+    leaf?: RecursiveLeaf | null;
+}
+
+interface RecursiveParent {
+    child: RecursiveChild;
+    siblings: RecursiveChild[];
+    not_derived: NestedStruct;
+}
@@ -0,0 +1,5 @@
+interface RenameAllTestStruct {
+    userId: number; // rust: user_id
+    mail: string; // rust: email_address
+    lastLogin?: string | null; // rust: last_login
+}
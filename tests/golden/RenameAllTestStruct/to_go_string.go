@@ -0,0 +1,5 @@
+type RenameAllTestStruct struct {
+    UserID uint64 `json:"userId"`
+    EmailAddress string `json:"mail"`
+    LastLogin *string `json:"lastLogin,omitempty"`
+}
@@ -0,0 +1,6 @@
+@dataclass_json
+@dataclass
+class ResultTestStruct:
+    outcome: Union[Dict[Literal["Ok"], int], Dict[Literal["Err"], str]]
+    retried: Optional[Union[Dict[Literal["Ok"], int], Dict[Literal["Err"], NestedStruct]]]
+
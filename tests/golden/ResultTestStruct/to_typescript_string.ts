@@ -0,0 +1,4 @@
+interface ResultTestStruct {
+    outcome: { Ok: number } | { Err: string };
+    retried?: { Ok: number } | { Err: NestedStruct } | null;
+}
@@ -0,0 +1,5 @@
+struct SerdeAttributesTestStruct {
+    user_name: String,
+    cache: Vec<u8>,
+    nickname: Option<String>
+}
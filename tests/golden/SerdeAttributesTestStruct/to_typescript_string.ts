@@ -0,0 +1,4 @@
+interface SerdeAttributesTestStruct {
+    userName: string;
+    nickname?: string | null;
+}
@@ -0,0 +1,5 @@
+struct ShortNameTestStruct {
+    home: Address,
+    previous: Vec<Address>,
+    lookup: HashMap<String,Option<Address>>
+}
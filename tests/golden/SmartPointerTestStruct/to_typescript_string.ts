@@ -0,0 +1,5 @@
+interface SmartPointerTestStruct {
+    values?: number[] | null;
+    shared: string;
+    child: NestedStruct;
+}
@@ -0,0 +1,5 @@
+enum class StatusTestEnum {
+    ACTIVE,
+    IN_PROGRESS,
+    DONE,
+}
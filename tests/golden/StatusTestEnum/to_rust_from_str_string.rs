@@ -0,0 +1,12 @@
+impl std::str::FromStr for StatusTestEnum {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Active" => Ok(Self::Active),
+            "InProgress" => Ok(Self::InProgress),
+            "Done" => Ok(Self::Done),
+            _ => Err(format!("unknown StatusTestEnum: {}", s)),
+        }
+    }
+}
@@ -0,0 +1,8 @@
+interface StringLikeTestStruct {
+    name: string;
+    label: string;
+    shared: string;
+    boxed: string;
+    aliases: string[];
+    scores: number[];
+}
@@ -0,0 +1,8 @@
+package main
+
+import "time"
+
+type SystemTimeTestStruct struct {
+    CreatedAt time.Time `json:"created_at"`
+    DeletedAt *time.Time `json:"deleted_at,omitempty"`
+}
@@ -0,0 +1,4 @@
+interface SystemTimeTestStruct {
+    created_at: string;
+    deleted_at?: string | null;
+}
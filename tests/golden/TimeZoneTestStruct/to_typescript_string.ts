@@ -0,0 +1,4 @@
+interface TimeZoneTestStruct {
+    time_zone: string; // IANA time zone name
+    fallback_time_zone?: string | null; // IANA time zone name
+}
@@ -0,0 +1,3 @@
+interface TsOverrideTestStruct {
+    id: Brand<string>;
+}
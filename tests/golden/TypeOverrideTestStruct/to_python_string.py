@@ -0,0 +1,6 @@
+@dataclass_json
+@dataclass
+class TypeOverrideTestStruct:
+    timestamp: datetime
+    count: int
+
@@ -0,0 +1,4 @@
+interface TypeOverrideTestStruct {
+    timestamp: Date;
+    count: number;
+}
@@ -0,0 +1,4 @@
+interface UnitTestStruct {
+    id: number;
+    ack: null;
+}
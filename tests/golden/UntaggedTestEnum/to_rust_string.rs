@@ -0,0 +1,7 @@
+enum UntaggedTestEnum {
+    Empty,
+    Radius(f64),
+    Point(i32,i32),
+    Rect { w: f64, h: f64 },
+    Nested(NestedStruct)
+}
@@ -0,0 +1 @@
+type UntaggedTestEnum = null | number | [number, number] | { w: number; height: number } | NestedStruct;
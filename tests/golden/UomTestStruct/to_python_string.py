@@ -0,0 +1,6 @@
+@dataclass_json
+@dataclass
+class UomTestStruct:
+    length: float  # Length (m)
+    lap_times: List[float]  # Time (s)
+
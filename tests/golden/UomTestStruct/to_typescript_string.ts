@@ -0,0 +1,4 @@
+interface UomTestStruct {
+    length: number; // Length (m)
+    lap_times: number[]; // Time (s)
+}
@@ -0,0 +1 @@
+UserId = NewType("UserId", int)
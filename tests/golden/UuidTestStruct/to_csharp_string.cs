@@ -0,0 +1,5 @@
+public class UuidTestStruct {
+    public Guid id;
+    public Guid? parent_id;
+    public List<Guid> child_ids;
+}
@@ -0,0 +1,5 @@
+interface UuidTestStruct {
+    id: string;
+    parent_id?: string | null;
+    child_ids: string[];
+}
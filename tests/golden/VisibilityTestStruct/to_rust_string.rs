@@ -0,0 +1,5 @@
+pub struct VisibilityTestStruct {
+    pub id: u64,
+    pub(crate) name: String,
+    secret: String
+}
@@ -0,0 +1,4 @@
+public class Wrapper<T> {
+    public T value;
+    public Stream<T> items;
+}
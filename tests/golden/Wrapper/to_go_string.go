@@ -0,0 +1,4 @@
+type Wrapper[T any] struct {
+    Value T `json:"value"`
+    Items []T `json:"items"`
+}
@@ -0,0 +1,6 @@
+@dataclass_json
+@dataclass
+class Wrapper[T]:
+    value: T
+    items: List[T]
+
@@ -0,0 +1,4 @@
+interface Wrapper<T> {
+    value: T;
+    items: T[];
+}
@@ -0,0 +1,13 @@
+interface ManifestLine {
+    quantity: number;
+}
+
+interface ManifestCustomer {
+    name: string;
+    last_order_lines?: ManifestLine[] | null;
+}
+
+interface ManifestOrder {
+    customer: ManifestCustomer;
+    lines: ManifestLine[];
+}
@@ -1,5 +1,13 @@
+#![allow(dead_code)]
+// These tests cover every output, so they need every language enabled.
+#![cfg(feature = "languages")]
+
+mod common;
+
 extern crate struct_to_string;
-use struct_to_string::StructToString;
+use common::assert_golden;
+use std::collections::HashSet;
+use struct_to_string::{Case, JavaCollection, Language, ResultStrategy, StructToString};
 
 #[derive(StructToString)]
 struct ComprehensiveTestStruct {
@@ -32,176 +40,2758 @@ enum AnEnum {
 
 #[test]
 fn to_rust() {
-    let expected = r#"struct ComprehensiveTestStruct {
-    int_field: i32,
-    uint_field: u32,
-    float_field: f64,
-    bool_field: bool,
-    char_field: char,
-    str_field: String,
-    option_field: Option<i32>,
-    array_field: [i32;3],
-    slice_field: Vec<i32>,
-    tuple_field: (i32,String),
-    tuple_struct_field: TupleStruct,
-    enum_field: AnEnum,
-    nested_struct_field: NestedStruct
-}"#;
-
     let struct_string = ComprehensiveTestStruct::to_rust_string();
 
-    println!("--- RUST CONVERSION --- ");
-    println!("--- WHAT WAS GENERATED --- ");
-    println!("{}", struct_string);
-    println!("--- WHAT WAS EXPECTED --- ");
-    println!("{}", expected);
-
-    assert_eq!(struct_string, expected);
+    assert_golden("ComprehensiveTestStruct/to_rust_string.rs", &struct_string);
 }
 
 #[test]
 fn to_typescript() {
-    let expected = r#"interface ComprehensiveTestStruct {
-    int_field: number;
-    uint_field: number;
-    float_field: number;
-    bool_field: boolean;
-    char_field: string;
-    str_field: string;
-    option_field?: number | null;
-    array_field: number[];
-    slice_field: number[];
-    tuple_field: [number, string];
-    tuple_struct_field: TupleStruct;
-    enum_field: AnEnum;
-    nested_struct_field: NestedStruct;
-}"#;
-
     let struct_string = ComprehensiveTestStruct::to_typescript_string();
 
-    println!("--- TS CONVERSION --- ");
-    println!("--- WHAT WAS GENERATED --- ");
-    println!("{}", struct_string);
-    println!("--- WHAT WAS EXPECTED --- ");
-    println!("{}", expected);
+    assert_golden(
+        "ComprehensiveTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+}
 
-    assert_eq!(struct_string, expected);
+#[test]
+fn to_zod() {
+    let struct_string = ComprehensiveTestStruct::to_zod_string();
+
+    assert_golden("ComprehensiveTestStruct/to_zod_string.ts", &struct_string);
 }
 
 #[test]
-fn to_python() {
-    let expected = r#"@dataclass_json
-@dataclass
-class ComprehensiveTestStruct:
-    int_field: int
-    uint_field: int
-    float_field: float
-    bool_field: bool
-    char_field: str
-    str_field: str
-    option_field: Optional[int]
-    array_field: List[int]
-    slice_field: List[int]
-    tuple_field: Tuple[int, str]
-    tuple_struct_field: TupleStruct
-    enum_field: AnEnum
-    nested_struct_field: NestedStruct
-"#;
+fn to_graphql_resolvers() {
+    let struct_string = ComprehensiveTestStruct::to_graphql_resolver_string();
+
+    assert_golden(
+        "ComprehensiveTestStruct/to_graphql_resolver_string.ts",
+        &struct_string,
+    );
+    // The interface extends the flattened type, so its resolvers accept the parent as well.
+    assert!(FlattenTestStruct::to_graphql_resolver_string()
+        .contains("    created_by: (parent: FlattenBaseStruct) => parent.created_by,\n"));
+}
 
+#[test]
+fn to_python() {
     let struct_string = ComprehensiveTestStruct::to_python_string();
 
-    println!("--- PYTHON CONVERSION --- ");
-    println!("--- WHAT WAS GENERATED --- ");
-    println!("{}", struct_string);
-    println!("--- WHAT WAS EXPECTED --- ");
-    println!("{}", expected);
+    assert_golden(
+        "ComprehensiveTestStruct/to_python_string.py",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_python_module() {
+    let struct_string = ComprehensiveTestStruct::to_python_module_string();
 
-    assert_eq!(struct_string, expected);
+    assert_golden(
+        "ComprehensiveTestStruct/to_python_module_string.py",
+        &struct_string,
+    );
+    // Only the imports the fields use are emitted.
+    assert!(ConstructorTestStruct::to_python_module_string().starts_with(
+        "from dataclasses import dataclass\nfrom typing import List\n\nfrom dataclasses_json import dataclass_json\n\n\n@dataclass_json\n"
+    ));
 }
 
 #[test]
 fn to_go() {
-    let expected = r#"type ComprehensiveTestStruct struct {
-    int_field int32
-    uint_field uint32
-    float_field float64
-    bool_field bool
-    char_field rune
-    str_field string
-    option_field *int32
-    array_field [3]int32
-    slice_field []int32
-    tuple_field struct{} // CANNOT CONVERT THIS TO THE GO PROGRAMMING LANGUAGE. TUPLES ARE UNSUPPORTED BY GO: (int32, string)
-    tuple_struct_field TupleStruct
-    enum_field AnEnum
-    nested_struct_field NestedStruct
-}"#;
-
     let struct_string = ComprehensiveTestStruct::to_go_string();
 
-    println!("--- GO CONVERSION --- ");
-    println!("--- WHAT WAS GENERATED --- ");
-    println!("{}", struct_string);
-    println!("--- WHAT WAS EXPECTED --- ");
-    println!("{}", expected);
-
-    assert_eq!(struct_string, expected);
+    assert_golden("ComprehensiveTestStruct/to_go_string.go", &struct_string);
 }
 
 #[test]
 fn to_java() {
-    let expected = r#"public class ComprehensiveTestStruct {
-    public int int_field;
-    public long uint_field;
-    public double float_field;
-    public boolean bool_field;
-    public char char_field;
-    public String str_field;
-    public Integer option_field;
-    public int[] array_field;
-    public List<Integer> slice_field;
-    public Tuple<Integer, String> tuple_field;
-    public TupleStruct tuple_struct_field;
-    public AnEnum enum_field;
-    public NestedStruct nested_struct_field;
-}"#;
-
     let struct_string = ComprehensiveTestStruct::to_java_string();
 
-    println!("--- JAVA CONVERSION --- ");
-    println!("--- WHAT WAS GENERATED --- ");
-    println!("{}", struct_string);
-    println!("--- WHAT WAS EXPECTED --- ");
-    println!("{}", expected);
+    assert_golden(
+        "ComprehensiveTestStruct/to_java_string.java",
+        &struct_string,
+    );
+}
 
-    assert_eq!(struct_string, expected);
+#[derive(StructToString)]
+struct EmptyTestStruct {}
+
+#[test]
+fn to_java_record() {
+    let struct_string = ComprehensiveTestStruct::to_java_record_string();
+
+    assert_golden(
+        "ComprehensiveTestStruct/to_java_record_string.java",
+        &struct_string,
+    );
+    assert_eq!(
+        EmptyTestStruct::to_java_record_string(),
+        "public record EmptyTestStruct() {}"
+    );
 }
 
 #[test]
 fn to_csharp() {
-    let expected = r#"public class ComprehensiveTestStruct {
-    public int int_field;
-    public uint uint_field;
-    public double float_field;
-    public bool bool_field;
-    public char char_field;
-    public string str_field;
-    public int? option_field;
-    public int[] array_field;
-    public List<int> slice_field;
-    public (int, string) tuple_field;
-    public TupleStruct tuple_struct_field;
-    public AnEnum enum_field;
-    public NestedStruct nested_struct_field;
-}"#;
-
     let struct_string = ComprehensiveTestStruct::to_csharp_string();
 
-    println!("--- C# CONVERSION --- ");
-    println!("--- WHAT WAS GENERATED --- ");
-    println!("{}", struct_string);
-    println!("--- WHAT WAS EXPECTED --- ");
-    println!("{}", expected);
+    assert_golden(
+        "ComprehensiveTestStruct/to_csharp_string.cs",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_csharp_record() {
+    let struct_string = ComprehensiveTestStruct::to_csharp_record_string();
+
+    assert_golden(
+        "ComprehensiveTestStruct/to_csharp_record_string.cs",
+        &struct_string,
+    );
+    assert_eq!(
+        EmptyTestStruct::to_csharp_record_string(),
+        "public record EmptyTestStruct();"
+    );
+}
+
+#[test]
+fn to_csharp_properties() {
+    let struct_string = ComprehensiveTestStruct::to_csharp_property_string();
+
+    assert_golden(
+        "ComprehensiveTestStruct/to_csharp_property_string.cs",
+        &struct_string,
+    );
+}
+
+#[derive(StructToString)]
+struct GoValidateTestStruct {
+    #[struct_to_string(go_validate = "required,email")]
+    email: String,
+    nickname: Option<String>,
+}
+
+#[test]
+fn to_go_with_validate_tags() {
+    let struct_string = GoValidateTestStruct::to_go_string();
+
+    assert_golden("GoValidateTestStruct/to_go_string.go", &struct_string);
+}
+
+#[derive(StructToString)]
+struct Wrapper<T> {
+    value: T,
+    items: Vec<T>,
+}
+
+#[derive(StructToString)]
+struct BorrowedWrapper<'a, T, const N: usize> {
+    values: [T; N],
+    marker: std::marker::PhantomData<&'a T>,
+}
+
+#[test]
+fn generic_struct_to_typescript() {
+    let struct_string = Wrapper::<i32>::to_typescript_string();
+
+    assert_golden("Wrapper/to_typescript_string.ts", &struct_string);
+}
+
+#[test]
+fn generic_struct_to_python() {
+    let struct_string = Wrapper::<i32>::to_python_string();
+
+    assert_golden("Wrapper/to_python_string.py", &struct_string);
+}
+
+#[test]
+fn generic_struct_to_go() {
+    let struct_string = Wrapper::<i32>::to_go_string();
+
+    assert_golden("Wrapper/to_go_string.go", &struct_string);
+}
+
+#[derive(StructToString)]
+struct GoGenericFieldTestStruct<T> {
+    wrapped: Wrapper<i32>,
+    nested: Option<Wrapper<Vec<String>>>,
+    forwarded: Wrapper<T>,
+    borrowed: BorrowedWrapper<'static, u8, 4>,
+    tags: HashSet<String>,
+    lock: std::sync::Mutex<u32>,
+}
+
+#[test]
+fn generic_field_types_to_go() {
+    assert_golden(
+        "GoGenericFieldTestStruct/to_go_string.go",
+        &GoGenericFieldTestStruct::<bool>::to_go_string(),
+    );
+}
+
+#[test]
+fn generic_struct_drops_lifetime_and_const_params() {
+    assert!(BorrowedWrapper::<i32, 3>::to_rust_string().starts_with("struct BorrowedWrapper<T> {"));
+    assert!(BorrowedWrapper::<i32, 3>::to_typescript_string()
+        .starts_with("interface BorrowedWrapper<T> {"));
+    assert!(BorrowedWrapper::<i32, 3>::to_go_string()
+        .starts_with("type BorrowedWrapper[T any] struct {\n    Values []T `json:\"values\"`\n"));
+    assert!(BorrowedWrapper::<i32, 3>::to_java_string()
+        .starts_with("public class BorrowedWrapper<T> {"));
+    assert!(BorrowedWrapper::<i32, 3>::to_csharp_string()
+        .starts_with("public class BorrowedWrapper<T> {"));
+}
+
+#[derive(StructToString)]
+struct DoubleOptionTestStruct {
+    maybe_missing: Option<Option<i32>>,
+}
+
+#[test]
+fn double_option_collapses() {
+    assert_eq!(
+        DoubleOptionTestStruct::to_typescript_string(),
+        "interface DoubleOptionTestStruct {\n    maybe_missing?: number | null;\n}"
+    );
+    assert_eq!(
+        DoubleOptionTestStruct::to_python_string(),
+        "@dataclass_json\n@dataclass\nclass DoubleOptionTestStruct:\n    maybe_missing: Optional[int]\n"
+    );
+    assert_eq!(
+        DoubleOptionTestStruct::to_go_string(),
+        "type DoubleOptionTestStruct struct {\n    MaybeMissing *int32 `json:\"maybe_missing,omitempty\"`\n}"
+    );
+    assert_eq!(
+        DoubleOptionTestStruct::to_java_string(),
+        "public class DoubleOptionTestStruct {\n    public Integer maybe_missing;\n}"
+    );
+    assert_eq!(
+        DoubleOptionTestStruct::to_csharp_string(),
+        "public class DoubleOptionTestStruct {\n    public int? maybe_missing;\n}"
+    );
+}
+
+mod bignum {
+    pub struct BigInt;
+    pub struct BigUint;
+    pub struct BigDecimal;
+}
+
+#[derive(StructToString)]
+struct BigNumberTestStruct {
+    signed: bignum::BigInt,
+    unsigned: bignum::BigUint,
+    amount: bignum::BigDecimal,
+}
+
+#[test]
+fn big_numbers_to_typescript() {
+    let struct_string = BigNumberTestStruct::to_typescript_string();
+
+    assert_golden(
+        "BigNumberTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+}
+
+#[test]
+fn big_numbers_to_python() {
+    let struct_string = BigNumberTestStruct::to_python_string();
+
+    assert_golden("BigNumberTestStruct/to_python_string.py", &struct_string);
+}
+
+#[test]
+fn big_numbers_to_go() {
+    let struct_string = BigNumberTestStruct::to_go_string();
+
+    assert_golden("BigNumberTestStruct/to_go_string.go", &struct_string);
+}
+
+#[test]
+fn big_numbers_to_java() {
+    let struct_string = BigNumberTestStruct::to_java_string();
+
+    assert_golden("BigNumberTestStruct/to_java_string.java", &struct_string);
+}
+
+#[test]
+fn big_numbers_to_csharp() {
+    let struct_string = BigNumberTestStruct::to_csharp_string();
+
+    assert_golden("BigNumberTestStruct/to_csharp_string.cs", &struct_string);
+}
+
+#[derive(StructToString)]
+struct SerdeAttributesTestStruct {
+    #[serde(rename = "userName")]
+    user_name: String,
+    #[serde(skip)]
+    cache: Vec<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nickname: Option<String>,
+}
+
+#[test]
+fn serde_skip_and_rename_to_typescript() {
+    let struct_string = SerdeAttributesTestStruct::to_typescript_string();
+
+    assert_golden(
+        "SerdeAttributesTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+}
 
-    assert_eq!(struct_string, expected);
+#[test]
+fn serde_skip_and_rename_keep_rust_definition() {
+    let struct_string = SerdeAttributesTestStruct::to_rust_string();
+
+    assert_golden(
+        "SerdeAttributesTestStruct/to_rust_string.rs",
+        &struct_string,
+    );
+}
+
+#[derive(StructToString)]
+struct CfgTestStruct {
+    always: i32,
+    #[cfg(test)]
+    test_only: i32,
+    #[cfg(all(test, not(target_os = "none")))]
+    nested_cfg: String,
+}
+
+#[test]
+fn cfg_attributes_preserved_in_rust() {
+    let struct_string = CfgTestStruct::to_rust_string();
+
+    assert_golden("CfgTestStruct/to_rust_string.rs", &struct_string);
+}
+
+#[test]
+fn to_openapi_schema() {
+    let struct_string = ComprehensiveTestStruct::to_openapi_schema_string();
+
+    assert_golden(
+        "ComprehensiveTestStruct/to_openapi_schema_string.yaml",
+        &struct_string,
+    );
+}
+
+#[derive(StructToString)]
+struct QueryParamsTestStruct {
+    page: u32,
+    per_page: Option<u32>,
+    tags: Vec<String>,
+}
+
+#[test]
+fn to_openapi_parameters() {
+    let struct_string = QueryParamsTestStruct::to_openapi_parameters_string();
+
+    assert_golden(
+        "QueryParamsTestStruct/to_openapi_parameters_string.yaml",
+        &struct_string,
+    );
+    assert!(struct_string.contains("  - name: per_page\n    in: query\n    required: false\n"));
+    assert_eq!(
+        EmptyTestStruct::to_openapi_parameters_string(),
+        "parameters: []"
+    );
+}
+
+#[derive(StructToString)]
+struct ConstructorTestStruct {
+    id: u64,
+    name: String,
+    tags: Vec<String>,
+}
+
+#[test]
+fn to_rust_new_impl() {
+    let struct_string = ConstructorTestStruct::to_rust_new_impl_string();
+
+    assert_golden(
+        "ConstructorTestStruct/to_rust_new_impl_string.rs",
+        &struct_string,
+    );
+}
+
+#[derive(StructToString)]
+#[struct_to_string(display = "{code}-{version:03} ({{draft}})")]
+struct DisplayTemplateTestStruct {
+    code: String,
+    version: u32,
+}
+
+#[test]
+fn to_rust_display_impl_from_template() {
+    let struct_string = DisplayTemplateTestStruct::to_rust_display_impl_string();
+
+    assert!(struct_string
+        .contains("        write!(f, \"{}-{:03} ({{draft}})\", self.code, self.version)\n"));
+    assert!(!struct_string.contains("writeln!"));
+    assert_golden(
+        "DisplayTemplateTestStruct/to_rust_display_impl_string.rs",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_rust_display_impl() {
+    let struct_string = ConstructorTestStruct::to_rust_display_impl_string();
+
+    assert_eq!(struct_string.matches("writeln!").count(), 3);
+    assert_golden(
+        "ConstructorTestStruct/to_rust_display_impl_string.rs",
+        &struct_string,
+    );
+}
+
+#[test]
+fn generic_rust_new_impl() {
+    assert_eq!(
+        Wrapper::<i32>::to_rust_new_impl_string(),
+        "impl<T> Wrapper<T> {\n    pub fn new(value: T, items: Vec<T>) -> Self {\n        Self { value, items }\n    }\n}"
+    );
+}
+
+#[derive(StructToString)]
+struct BoundedGenericTestStruct<'a, T: Clone + Default, const N: usize>
+where
+    T: std::fmt::Debug,
+{
+    label: &'a str,
+    values: [T; N],
+}
+
+#[test]
+fn bounded_generic_rust_new_impl() {
+    assert_eq!(
+        BoundedGenericTestStruct::<u8, 2>::to_rust_new_impl_string(),
+        "impl<'a, T: Clone + Default, const N: usize> BoundedGenericTestStruct<'a, T, N> where T: std::fmt::Debug {\n    pub fn new(label: &'a str, values: [T;N]) -> Self {\n        Self { label, values }\n    }\n}"
+    );
+}
+
+#[test]
+fn bounded_generic_rust_display_impl() {
+    assert!(BoundedGenericTestStruct::<u8, 2>::to_rust_display_impl_string().starts_with(
+        "impl<'a, T: Clone + Default + std::fmt::Debug, const N: usize> std::fmt::Display for BoundedGenericTestStruct<'a, T, N> where T: std::fmt::Debug {\n"
+    ));
+}
+
+#[derive(StructToString)]
+struct TypeOverrideTestStruct {
+    #[struct_to_string(typescript = "Date", python = "datetime")]
+    timestamp: i64,
+    count: i64,
+}
+
+#[test]
+fn type_override_to_typescript() {
+    let struct_string = TypeOverrideTestStruct::to_typescript_string();
+
+    assert_golden(
+        "TypeOverrideTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+}
+
+#[test]
+fn type_override_to_python() {
+    let struct_string = TypeOverrideTestStruct::to_python_string();
+
+    assert_golden("TypeOverrideTestStruct/to_python_string.py", &struct_string);
+}
+
+#[derive(StructToString)]
+struct TsOverrideTestStruct {
+    #[struct_to_string(ts = "Brand<string>", go = "UserID")]
+    id: String,
+}
+
+#[test]
+fn ts_shorthand_override() {
+    let struct_string = TsOverrideTestStruct::to_typescript_string();
+
+    assert_golden(
+        "TsOverrideTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+    assert!(TsOverrideTestStruct::to_go_string().contains("    ID UserID `json:\"id\"`\n"));
+}
+
+#[test]
+fn type_override_leaves_other_languages_alone() {
+    assert!(TypeOverrideTestStruct::to_rust_string().contains("    timestamp: i64,\n"));
+    assert!(TypeOverrideTestStruct::to_go_string()
+        .contains("    Timestamp int64 `json:\"timestamp\"`\n"));
+    assert!(TypeOverrideTestStruct::to_java_string().contains("    public long timestamp;\n"));
+    assert!(TypeOverrideTestStruct::to_csharp_string().contains("    public long timestamp;\n"));
+}
+
+#[test]
+fn referenced_types() {
+    assert_eq!(
+        ComprehensiveTestStruct::referenced_types(),
+        vec!["TupleStruct", "AnEnum", "NestedStruct"]
+    );
+    assert!(Wrapper::<i32>::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct ManifestOrder {
+    customer: ManifestCustomer,
+    lines: Vec<ManifestLine>,
+}
+
+#[derive(StructToString)]
+struct ManifestLine {
+    quantity: u32,
+}
+
+#[derive(StructToString)]
+struct ManifestCustomer {
+    name: String,
+    last_order_lines: Option<Vec<ManifestLine>>,
+}
+
+#[test]
+fn manifest_in_dependency_order() {
+    let manifest = struct_to_string::struct_to_string_manifest! {
+        [ManifestOrder, ManifestCustomer, ManifestLine],
+        lang = TypeScript
+    };
+
+    assert_golden("struct_to_string_manifest/typescript.ts", &manifest);
+}
+
+mod uom {
+    pub mod si {
+        pub mod f64 {
+            pub struct Length;
+            pub struct Time;
+        }
+    }
+}
+
+#[derive(StructToString)]
+struct UomTestStruct {
+    length: uom::si::f64::Length,
+    lap_times: Vec<uom::si::f64::Time>,
+}
+
+#[test]
+fn uom_quantities_to_typescript() {
+    let struct_string = UomTestStruct::to_typescript_string();
+
+    assert_golden("UomTestStruct/to_typescript_string.ts", &struct_string);
+}
+
+#[test]
+fn uom_quantities_to_python() {
+    let struct_string = UomTestStruct::to_python_string();
+
+    assert_golden("UomTestStruct/to_python_string.py", &struct_string);
+}
+
+#[test]
+fn uom_quantities_in_other_languages() {
+    assert!(UomTestStruct::to_go_string()
+        .contains("    Length float64 `json:\"length\"` // Length (m)\n"));
+    assert!(UomTestStruct::to_java_string().contains("    public double length; // Length (m)\n"));
+    assert!(UomTestStruct::to_csharp_string().contains("    public double length; // Length (m)\n"));
+    assert!(UomTestStruct::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct Time {
+    hours: u8,
+    minutes: u8,
+}
+
+#[derive(StructToString)]
+struct LocalQuantityNameTestStruct {
+    start: Time,
+}
+
+#[test]
+fn types_named_like_uom_quantities_stay_custom() {
+    assert!(LocalQuantityNameTestStruct::to_typescript_string().contains("    start: Time;\n"));
+    assert_eq!(
+        LocalQuantityNameTestStruct::referenced_types(),
+        vec!["Time"]
+    );
+    assert!(
+        LocalQuantityNameTestStruct::to_typescript_string_recursive()
+            .starts_with("interface Time {\n")
+    );
+}
+
+// Stands in for the `half` crate, which only the type names matter for.
+#[allow(non_camel_case_types)]
+mod half {
+    pub struct f16;
+    pub struct bf16;
+}
+
+#[derive(StructToString)]
+struct HalfPrecisionTestStruct {
+    weight: half::f16,
+    activations: Vec<half::bf16>,
+}
+
+#[test]
+fn half_precision_floats() {
+    assert_golden(
+        "HalfPrecisionTestStruct/to_typescript_string.ts",
+        &HalfPrecisionTestStruct::to_typescript_string(),
+    );
+    assert!(HalfPrecisionTestStruct::to_python_string()
+        .contains("    weight: float  # f16 (reduced precision)\n"));
+    assert!(HalfPrecisionTestStruct::to_go_string().contains(
+        "    Activations []float32 `json:\"activations\"` // bf16 (reduced precision)\n"
+    ));
+    assert!(HalfPrecisionTestStruct::referenced_types().is_empty());
+}
+
+#[test]
+fn to_typescript_type_alias() {
+    let struct_string = ConstructorTestStruct::to_typescript_type_alias_string(false);
+
+    assert_golden(
+        "ConstructorTestStruct/to_typescript_type_alias_string_false.ts",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_exported_typescript_type_alias() {
+    assert!(ConstructorTestStruct::to_typescript_type_alias_string(true)
+        .starts_with("export type ConstructorTestStruct = {\n"));
+}
+
+mod chrono {
+    pub struct Utc;
+    pub struct DateTime<Tz>(std::marker::PhantomData<Tz>);
+    pub struct NaiveDate;
+}
+
+#[derive(StructToString)]
+struct GoPackageTestStruct {
+    created_at: chrono::DateTime<chrono::Utc>,
+    count: i32,
+}
+
+#[derive(StructToString)]
+struct GoMultipleImportsTestStruct {
+    deleted_at: Option<chrono::DateTime<chrono::Utc>>,
+    balance: i128,
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn to_go_with_package_imports_time() {
+    let struct_string = GoPackageTestStruct::to_go_string_with_package("models");
+
+    assert_golden(
+        "GoPackageTestStruct/to_go_string_with_package_models.go",
+        &struct_string,
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn to_go_with_package_groups_imports() {
+    let struct_string = GoMultipleImportsTestStruct::to_go_string_with_package("models");
+
+    assert_golden(
+        "GoMultipleImportsTestStruct/to_go_string_with_package_models.go",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_go_with_package_without_imports() {
+    assert!(ConstructorTestStruct::to_go_string_with_package("models")
+        .starts_with("package models\n\ntype ConstructorTestStruct struct {\n"));
+}
+
+#[test]
+fn to_exported_typescript() {
+    let struct_string = ConstructorTestStruct::to_typescript_string_with_export(true);
+
+    assert_golden(
+        "ConstructorTestStruct/to_typescript_string_with_export_true.ts",
+        &struct_string,
+    );
+    assert_eq!(
+        ConstructorTestStruct::to_typescript_string_with_export(false),
+        ConstructorTestStruct::to_typescript_string()
+    );
+}
+
+#[test]
+fn to_internal_csharp() {
+    let struct_string = ConstructorTestStruct::to_csharp_string_with_access_modifier("internal");
+
+    assert_golden(
+        "ConstructorTestStruct/to_csharp_string_with_access_modifier_internal.cs",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_kotlin() {
+    let struct_string = ComprehensiveTestStruct::to_kotlin_string();
+
+    assert_golden(
+        "ComprehensiveTestStruct/to_kotlin_string.kt",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_kotlin_mutable() {
+    let struct_string = ConstructorTestStruct::string_builder()
+        .language(Language::Kotlin)
+        .mutable(true)
+        .build();
+
+    assert_golden(
+        "ConstructorTestStruct/string_builder_mutable.kt",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_swift() {
+    let struct_string = ComprehensiveTestStruct::to_swift_string();
+
+    assert_golden(
+        "ComprehensiveTestStruct/to_swift_string.swift",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_swift_mutable() {
+    assert!(ConstructorTestStruct::string_builder()
+        .language(Language::Swift)
+        .mutable(true)
+        .build()
+        .contains("    var id: UInt64\n    var name: String\n"));
+}
+
+#[derive(StructToString)]
+struct RecursiveParent {
+    child: RecursiveChild,
+    siblings: Vec<RecursiveChild>,
+    not_derived: NestedStruct,
+}
+
+#[derive(StructToString)]
+struct RecursiveChild {
+    leaf: Option<RecursiveLeaf>,
+}
+
+#[derive(StructToString)]
+struct RecursiveLeaf {
+    value: i32,
+}
+
+#[derive(StructToString)]
+struct CyclicA {
+    b: Vec<CyclicB>,
+}
+
+#[derive(StructToString)]
+struct CyclicB {
+    a: Option<Vec<CyclicA>>,
+}
+
+#[test]
+fn to_typescript_recursive() {
+    let struct_string = RecursiveParent::to_typescript_string_recursive();
+
+    assert_golden(
+        "RecursiveParent/to_typescript_string_recursive.ts",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_python_recursive() {
+    let struct_string = RecursiveChild::to_python_string_recursive();
+
+    assert_golden(
+        "RecursiveChild/to_python_string_recursive.py",
+        &struct_string,
+    );
+}
+
+#[test]
+fn recursive_output_stops_at_cycles() {
+    assert_eq!(
+        CyclicA::to_rust_string_recursive(),
+        format!(
+            "{}\n\n{}",
+            CyclicB::to_rust_string(),
+            CyclicA::to_rust_string()
+        )
+    );
+}
+
+mod first_module {
+    use struct_to_string::StructToString;
+
+    #[derive(StructToString)]
+    pub struct SameNameTestStruct {
+        pub id: u32,
+    }
+}
+
+mod second_module {
+    use struct_to_string::StructToString;
+
+    #[derive(StructToString)]
+    pub struct SameNameTestStruct {
+        pub name: String,
+    }
+}
+
+#[derive(StructToString)]
+struct SameNameParentTestStruct {
+    first: first_module::SameNameTestStruct,
+    second: second_module::SameNameTestStruct,
+}
+
+#[derive(StructToString)]
+struct GenericInstancesTestStruct {
+    leaf: Wrapper<RecursiveLeaf>,
+    child: Wrapper<RecursiveChild>,
+}
+
+#[test]
+fn recursive_output_tells_types_apart_by_path_and_arguments() {
+    let python = SameNameParentTestStruct::to_python_string_recursive();
+    assert!(python.contains("    id: int\n"));
+    assert!(python.contains("    name: str\n"));
+
+    let typescript = GenericInstancesTestStruct::to_typescript_string_recursive();
+    assert_eq!(typescript.matches("interface Wrapper<T>").count(), 1);
+    assert!(typescript.contains("interface RecursiveLeaf {"));
+    assert!(typescript.contains("interface RecursiveChild {"));
+}
+
+#[test]
+fn csharp_optional_reference_types_are_nullable() {
+    assert!(RecursiveChild::to_csharp_string().contains("    public RecursiveLeaf? leaf;\n"));
+    assert!(CyclicB::to_csharp_string().contains("    public List<CyclicA>? a;\n"));
+    assert!(
+        SerdeAttributesTestStruct::to_csharp_string().contains("    public string? nickname;\n")
+    );
+}
+
+#[derive(StructToString)]
+struct MapTestStruct {
+    #[struct_to_string(map_keys("Red", "Green", "Blue"))]
+    color_counts: std::collections::HashMap<String, u32>,
+    labels: std::collections::BTreeMap<String, Option<String>>,
+}
+
+#[test]
+fn map_to_typescript() {
+    let struct_string = MapTestStruct::to_typescript_string();
+
+    assert_golden("MapTestStruct/to_typescript_string.ts", &struct_string);
+}
+
+#[test]
+fn map_to_other_languages() {
+    assert!(MapTestStruct::to_go_string()
+        .contains("    ColorCounts map[string]uint32 `json:\"color_counts\"`\n"));
+    assert!(MapTestStruct::to_python_string().contains("    color_counts: Dict[str, int]\n"));
+    assert!(
+        MapTestStruct::to_java_string().contains("    public Map<String, Long> color_counts;\n")
+    );
+    assert!(MapTestStruct::to_csharp_string()
+        .contains("    public Dictionary<string, uint> color_counts;\n"));
+    assert!(MapTestStruct::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct SystemTimeTestStruct {
+    created_at: std::time::SystemTime,
+    deleted_at: Option<std::time::SystemTime>,
+}
+
+#[test]
+fn system_time_to_typescript() {
+    let struct_string = SystemTimeTestStruct::to_typescript_string();
+
+    assert_golden(
+        "SystemTimeTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+}
+
+#[test]
+fn system_time_to_go() {
+    let struct_string = SystemTimeTestStruct::to_go_string_with_package("main");
+
+    assert_golden(
+        "SystemTimeTestStruct/to_go_string_with_package_main.go",
+        &struct_string,
+    );
+}
+
+#[test]
+fn system_time_to_other_languages() {
+    assert!(
+        SystemTimeTestStruct::to_python_string().contains("    created_at: datetime.datetime\n")
+    );
+    assert!(SystemTimeTestStruct::to_java_string().contains("    public Instant created_at;\n"));
+    assert!(SystemTimeTestStruct::to_csharp_string()
+        .contains("    public DateTimeOffset created_at;\n"));
+    assert!(SystemTimeTestStruct::referenced_types().is_empty());
+}
+
+#[test]
+fn to_string_in_matches_individual_methods() {
+    for language in Language::ALL {
+        let expected = match language {
+            Language::Rust => ComprehensiveTestStruct::to_rust_string(),
+            Language::TypeScript => ComprehensiveTestStruct::to_typescript_string(),
+            Language::Python => ComprehensiveTestStruct::to_python_string(),
+            Language::Go => ComprehensiveTestStruct::to_go_string(),
+            Language::Java => ComprehensiveTestStruct::to_java_string(),
+            Language::CSharp => ComprehensiveTestStruct::to_csharp_string(),
+            Language::Kotlin => ComprehensiveTestStruct::to_kotlin_string(),
+            Language::Swift => ComprehensiveTestStruct::to_swift_string(),
+            Language::Cpp => ComprehensiveTestStruct::to_cpp_string(),
+            Language::Flow => ComprehensiveTestStruct::to_flow_string(),
+            Language::Elixir => ComprehensiveTestStruct::to_elixir_string(),
+            Language::OCaml => ComprehensiveTestStruct::to_ocaml_string(),
+            Language::FSharp => ComprehensiveTestStruct::to_fsharp_string(),
+        };
+
+        assert_eq!(ComprehensiveTestStruct::to_string_in(language), expected);
+    }
+}
+
+#[derive(StructToString)]
+struct CharTestStruct {
+    initial: char,
+    letters: Vec<char>,
+    middle_initial: Option<char>,
+}
+
+#[test]
+fn char_to_typescript() {
+    let struct_string = CharTestStruct::to_typescript_string();
+
+    assert_golden("CharTestStruct/to_typescript_string.ts", &struct_string);
+}
+
+#[test]
+fn char_to_python() {
+    let struct_string = CharTestStruct::to_python_string();
+
+    assert_golden("CharTestStruct/to_python_string.py", &struct_string);
+}
+
+#[test]
+fn char_in_collections_to_other_languages() {
+    let go = CharTestStruct::to_go_string();
+    assert!(go.contains("    Letters []rune `json:\"letters\"`\n"));
+    assert!(go.contains("    MiddleInitial *rune `json:\"middle_initial,omitempty\"`\n"));
+    let java = CharTestStruct::to_java_string();
+    assert!(java.contains("    public List<Character> letters;\n"));
+    assert!(java.contains("    public Character middle_initial;\n"));
+    let csharp = CharTestStruct::to_csharp_string();
+    assert!(csharp.contains("    public List<char> letters;\n"));
+    assert!(csharp.contains("    public char? middle_initial;\n"));
+}
+
+#[derive(StructToString)]
+pub struct VisibilityTestStruct {
+    pub id: u64,
+    pub(crate) name: String,
+    secret: String,
+}
+
+#[test]
+fn visibility_to_rust() {
+    let struct_string = VisibilityTestStruct::to_rust_string();
+
+    assert_golden("VisibilityTestStruct/to_rust_string.rs", &struct_string);
+}
+
+#[derive(StructToString)]
+#[struct_to_string(pub_only)]
+pub struct PubOnlyTestStruct {
+    pub id: u64,
+    pub(crate) name: String,
+    secret: String,
+}
+
+#[test]
+fn pub_only_skips_private_fields() {
+    assert_eq!(
+        PubOnlyTestStruct::to_typescript_string(),
+        "interface PubOnlyTestStruct {\n    id: number;\n}"
+    );
+    assert!(PubOnlyTestStruct::to_rust_string().ends_with("    secret: String\n}"));
+    assert!(VisibilityTestStruct::to_typescript_string().contains("    secret: string;\n"));
+}
+
+#[derive(StructToString)]
+#[struct_to_string(indent = "  ")]
+struct IndentTestStruct {
+    id: u32,
+    tags: Vec<String>,
+}
+
+#[test]
+fn indent_attribute() {
+    assert_eq!(
+        IndentTestStruct::to_typescript_string(),
+        "interface IndentTestStruct {\n  id: number;\n  tags: string[];\n}"
+    );
+    assert_eq!(
+        IndentTestStruct::to_rust_string(),
+        "struct IndentTestStruct {\n  id: u32,\n  tags: Vec<String>\n}"
+    );
+    assert!(IndentTestStruct::to_python_string().ends_with(":\n  id: int\n  tags: List[str]\n"));
+    assert!(IndentTestStruct::to_zod_string().contains("\n  id: z.number(),\n"));
+}
+
+#[derive(StructToString)]
+#[struct_to_string(typescript_option = "Option")]
+struct TypeScriptOptionTestStruct {
+    id: u32,
+    score: Option<i32>,
+    scores: Vec<Option<f64>>,
+}
+
+#[test]
+fn typescript_option_wrapper() {
+    assert_eq!(
+        TypeScriptOptionTestStruct::to_typescript_string(),
+        "interface TypeScriptOptionTestStruct {\n    id: number;\n    score?: Option<number>;\n    scores: Option<number>[];\n}"
+    );
+    assert!(TypeScriptOptionTestStruct::to_python_string().contains("    score: Optional[int]\n"));
+}
+
+#[derive(StructToString)]
+struct SmartPointerTestStruct {
+    values: Option<Box<[i32]>>,
+    shared: std::sync::Arc<String>,
+    child: Box<NestedStruct>,
+}
+
+#[test]
+fn smart_pointers_to_typescript() {
+    let struct_string = SmartPointerTestStruct::to_typescript_string();
+
+    assert_golden(
+        "SmartPointerTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+}
+
+#[test]
+fn smart_pointers_to_other_languages() {
+    assert!(
+        SmartPointerTestStruct::to_python_string().contains("    values: Optional[List[int]]\n")
+    );
+    assert!(
+        SmartPointerTestStruct::to_go_string().contains("    Shared string `json:\"shared\"`\n")
+    );
+    assert_eq!(
+        SmartPointerTestStruct::referenced_types(),
+        vec!["NestedStruct"]
+    );
+}
+
+fn document<T: StructToString>() -> Vec<String> {
+    Language::ALL
+        .iter()
+        .map(|language| T::to_string_in(*language))
+        .collect()
+}
+
+#[test]
+fn methods_through_the_trait() {
+    assert_eq!(
+        document::<ConstructorTestStruct>()[1],
+        ConstructorTestStruct::to_typescript_string()
+    );
+    assert_eq!(
+        <Wrapper<i32> as StructToString>::to_go_string(),
+        Wrapper::<i32>::to_go_string()
+    );
+}
+
+#[derive(StructToString)]
+enum StatusTestEnum {
+    Active,
+    InProgress,
+    #[serde(rename = "done")]
+    Done,
+}
+
+#[test]
+fn unit_enum_to_kotlin() {
+    let enum_string = StatusTestEnum::to_kotlin_string();
+
+    assert_golden("StatusTestEnum/to_kotlin_string.kt", &enum_string);
+}
+
+#[test]
+fn unit_enum_to_other_languages() {
+    assert_eq!(
+        StatusTestEnum::to_rust_string(),
+        "enum StatusTestEnum {\n    Active,\n    InProgress,\n    Done\n}"
+    );
+    assert_eq!(
+        StatusTestEnum::to_typescript_string(),
+        r#"type StatusTestEnum = "Active" | "InProgress" | "done";"#
+    );
+    assert!(StatusTestEnum::to_python_string().contains("    IN_PROGRESS = \"InProgress\"\n"));
+    assert!(StatusTestEnum::to_go_string()
+        .contains("    StatusTestEnumDone StatusTestEnum = \"done\"\n"));
+    assert!(StatusTestEnum::to_swift_string().contains("    case inProgress = \"InProgress\"\n"));
+}
+
+mod http {
+    pub struct StatusCode;
+    pub struct Method;
+    pub struct HeaderMap;
+}
+
+#[derive(StructToString)]
+struct HttpTestStruct {
+    status: http::StatusCode,
+    method: http::Method,
+    headers: http::HeaderMap,
+}
+
+#[test]
+fn http_types_to_typescript() {
+    let struct_string = HttpTestStruct::to_typescript_string();
+
+    assert_golden("HttpTestStruct/to_typescript_string.ts", &struct_string);
+}
+
+#[test]
+fn http_types_to_python() {
+    let struct_string = HttpTestStruct::to_python_string();
+
+    assert_golden("HttpTestStruct/to_python_string.py", &struct_string);
+}
+
+#[test]
+fn http_types_are_not_referenced() {
+    assert!(HttpTestStruct::referenced_types().is_empty());
+    assert!(HttpTestStruct::to_go_string()
+        .contains("    Headers map[string]string `json:\"headers\"`\n"));
+}
+
+#[derive(StructToString)]
+enum Method {
+    Card,
+    Cash,
+}
+
+#[derive(StructToString)]
+struct LocalHttpNameTestStruct {
+    payment_method: Method,
+}
+
+#[test]
+fn types_named_like_http_types_stay_custom() {
+    assert!(
+        LocalHttpNameTestStruct::to_typescript_string().contains("    payment_method: Method;\n")
+    );
+    assert_eq!(LocalHttpNameTestStruct::referenced_types(), vec!["Method"]);
+    assert!(
+        LocalHttpNameTestStruct::to_json_schema_string().contains(r##""$ref": "#/$defs/Method""##)
+    );
+}
+
+#[test]
+fn to_all_strings() {
+    let all_strings = ConstructorTestStruct::to_all_strings();
+
+    // The languages come sorted, so that e.g. tabs built from them are in a stable order.
+    let keys: Vec<&str> = all_strings.keys().copied().collect();
+    assert_eq!(
+        keys,
+        vec![
+            "cpp",
+            "csharp",
+            "elixir",
+            "flow",
+            "fsharp",
+            "go",
+            "java",
+            "kotlin",
+            "ocaml",
+            "python",
+            "rust",
+            "swift",
+            "typescript"
+        ]
+    );
+    assert_eq!(all_strings["rust"], ConstructorTestStruct::to_rust_string());
+    assert_eq!(
+        all_strings["typescript"],
+        ConstructorTestStruct::to_typescript_string()
+    );
+}
+
+mod time {
+    pub struct OffsetDateTime;
+}
+
+#[derive(StructToString)]
+struct DateTypesTestStruct {
+    created_at: chrono::DateTime<chrono::Utc>,
+    birthday: chrono::NaiveDate,
+    expires_at: time::OffsetDateTime,
+    #[struct_to_string(as = "number", python = "float")]
+    updated_at: chrono::DateTime<chrono::Utc>,
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn date_types_to_typescript() {
+    let struct_string = DateTypesTestStruct::to_typescript_string();
+
+    assert_golden(
+        "DateTypesTestStruct/to_typescript_string.ts",
+        &struct_string,
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn date_types_to_python() {
+    let struct_string = DateTypesTestStruct::to_python_string();
+
+    assert_golden("DateTypesTestStruct/to_python_string.py", &struct_string);
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn date_types_to_java() {
+    let struct_string = DateTypesTestStruct::to_java_string();
+
+    assert_golden("DateTypesTestStruct/to_java_string.java", &struct_string);
+}
+
+#[cfg(not(feature = "chrono"))]
+#[test]
+fn chrono_types_without_the_feature() {
+    assert!(DateTypesTestStruct::to_typescript_string().contains("    created_at: DateTime;\n"));
+    assert!(DateTypesTestStruct::to_elixir_string().contains("    birthday: NaiveDate.t(),\n"));
+    assert!(DateTypesTestStruct::to_ocaml_string().contains("  birthday : naive_date;\n"));
+    assert_eq!(
+        DateTypesTestStruct::referenced_types(),
+        vec!["DateTime", "Utc", "NaiveDate"]
+    );
+}
+
+mod chrono_tz {
+    pub struct Tz;
+}
+
+#[derive(StructToString)]
+struct TimeZoneTestStruct {
+    time_zone: chrono_tz::Tz,
+    fallback_time_zone: Option<chrono_tz::Tz>,
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn time_zones_are_strings() {
+    assert_golden(
+        "TimeZoneTestStruct/to_typescript_string.ts",
+        &TimeZoneTestStruct::to_typescript_string(),
+    );
+    assert!(TimeZoneTestStruct::to_go_string()
+        .contains("    TimeZone string `json:\"time_zone\"` // IANA time zone name\n"));
+    assert!(TimeZoneTestStruct::to_java_string()
+        .contains("    public String time_zone; // IANA time zone name\n"));
+    assert!(TimeZoneTestStruct::to_openapi_schema_string()
+        .contains("    time_zone:\n      type: string\n"));
+    assert!(TimeZoneTestStruct::referenced_types().is_empty());
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn as_attribute_leaves_rust_alone() {
+    assert!(DateTypesTestStruct::to_rust_string()
+        .ends_with("    updated_at: chrono::DateTime<chrono::Utc>\n}"));
+    assert!(
+        DateTypesTestStruct::to_csharp_string().contains("    public DateTimeOffset created_at;\n")
+    );
+}
+
+#[derive(StructToString)]
+#[struct_to_string(csharp_prefix = "I", java_suffix = "DTO")]
+struct NameAffixTestStruct {
+    id: u64,
+}
+
+#[test]
+fn name_affixes_to_csharp_and_java() {
+    assert_golden(
+        "NameAffixTestStruct/to_csharp_string.cs",
+        &NameAffixTestStruct::to_csharp_string(),
+    );
+    assert_golden(
+        "NameAffixTestStruct/to_java_string.java",
+        &NameAffixTestStruct::to_java_string(),
+    );
+    assert!(
+        NameAffixTestStruct::to_typescript_string().starts_with("interface NameAffixTestStruct {")
+    );
+    assert!(NameAffixTestStruct::to_rust_string().starts_with("struct NameAffixTestStruct {"));
+}
+
+mod uuid {
+    pub struct Uuid;
+}
+
+#[derive(StructToString)]
+struct UuidTestStruct {
+    id: uuid::Uuid,
+    parent_id: Option<uuid::Uuid>,
+    child_ids: Vec<uuid::Uuid>,
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_to_typescript() {
+    let struct_string = UuidTestStruct::to_typescript_string();
+
+    assert_golden("UuidTestStruct/to_typescript_string.ts", &struct_string);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_to_csharp() {
+    let struct_string = UuidTestStruct::to_csharp_string();
+
+    assert_golden("UuidTestStruct/to_csharp_string.cs", &struct_string);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_to_openapi() {
+    let struct_string = UuidTestStruct::to_openapi_schema_string();
+
+    assert_golden(
+        "UuidTestStruct/to_openapi_schema_string.yaml",
+        &struct_string,
+    );
+}
+
+#[cfg(not(feature = "uuid"))]
+#[test]
+fn uuid_without_the_feature() {
+    assert!(UuidTestStruct::to_typescript_string().contains("    id: Uuid;\n"));
+    assert!(UuidTestStruct::to_elixir_string().contains("    id: Uuid.t(),\n"));
+    assert!(UuidTestStruct::to_ocaml_string().contains("  id : uuid;\n"));
+    assert!(UuidTestStruct::to_csv_schema_string()
+        .unwrap_err()
+        .starts_with("field `id` of type `uuid::Uuid`"));
+    assert_eq!(UuidTestStruct::referenced_types(), vec!["Uuid"]);
+}
+
+#[cfg(feature = "uuid")]
+#[test]
+fn uuid_to_other_languages() {
+    assert!(UuidTestStruct::to_python_string().contains("    parent_id: Optional[uuid.UUID]\n"));
+    assert!(UuidTestStruct::to_python_module_string().starts_with("import uuid\n"));
+    assert!(UuidTestStruct::to_go_string().contains("    ChildIds []string `json:\"child_ids\"`\n"));
+    assert!(UuidTestStruct::to_java_string().contains("    public List<UUID> child_ids;\n"));
+    assert!(UuidTestStruct::referenced_types().is_empty());
+}
+
+#[test]
+fn unit_enum_to_rust_from_str() {
+    let enum_string = StatusTestEnum::to_rust_from_str_string();
+
+    assert_golden("StatusTestEnum/to_rust_from_str_string.rs", &enum_string);
+    assert!(enum_string.contains("            \"Active\" => Ok(Self::Active),\n"));
+    assert!(enum_string.contains("            \"InProgress\" => Ok(Self::InProgress),\n"));
+    assert!(enum_string.contains("            \"Done\" => Ok(Self::Done),\n"));
+}
+
+#[derive(StructToString)]
+enum PriorityTestEnum {
+    Low = 1,
+    Medium,
+    High = 10,
+}
+
+#[test]
+fn unit_enum_to_proto() {
+    let enum_string = StatusTestEnum::to_proto_string();
+
+    assert_golden("StatusTestEnum/to_proto_string.proto", &enum_string);
+}
+
+#[test]
+fn unit_enum_with_discriminants_to_proto() {
+    let enum_string = PriorityTestEnum::to_proto_string();
+
+    assert_golden("PriorityTestEnum/to_proto_string.proto", &enum_string);
+}
+
+#[derive(StructToString)]
+enum SignedTestEnum {
+    Below = -1,
+    Zero = 0,
+    Above = 1,
+}
+
+#[test]
+fn unit_enum_with_negative_discriminants_to_proto() {
+    assert_eq!(
+        SignedTestEnum::to_proto_string(),
+        "enum SignedTestEnum {\n    ZERO = 0;\n    BELOW = -1;\n    ABOVE = 1;\n}"
+    );
+}
+
+const FLAG_BASE: u32 = 4;
+
+// Discriminants the macro can't evaluate only leave out `to_proto_string`.
+#[derive(StructToString)]
+#[repr(u32)]
+enum FlagsTestEnum {
+    Read = 1 << 0,
+    Write = 1 << 1,
+    Execute = FLAG_BASE,
+}
+
+#[test]
+fn unit_enum_with_unevaluated_discriminants() {
+    assert_eq!(
+        FlagsTestEnum::to_typescript_string(),
+        "type FlagsTestEnum = \"Read\" | \"Write\" | \"Execute\";"
+    );
+    assert!(FlagsTestEnum::to_rust_string().contains("    Write = 1 << 1,\n"));
+}
+
+#[derive(StructToString)]
+#[repr(u8)]
+enum ReprTestEnum {
+    Ping = 1,
+    Pong = 2,
+}
+
+#[test]
+fn unit_enum_repr_to_rust() {
+    assert_eq!(
+        ReprTestEnum::to_rust_string(),
+        "#[repr(u8)]\nenum ReprTestEnum {\n    Ping = 1,\n    Pong = 2\n}"
+    );
+    assert!(ReprTestEnum::to_rust_string_with_derive(true)
+        .starts_with("#[derive(StructToString)]\n#[repr(u8)]\nenum ReprTestEnum {\n"));
+}
+
+#[derive(StructToString)]
+enum EmptyTestEnum {}
+
+#[derive(StructToString)]
+enum SkippedTestEnum {
+    #[serde(skip)]
+    Internal,
+}
+
+#[derive(StructToString)]
+#[serde(untagged)]
+enum SkippedUntaggedTestEnum {
+    #[serde(skip)]
+    Raw(Vec<u8>),
+}
+
+#[test]
+fn uninhabited_enum_to_bottom_type() {
+    assert_eq!(
+        EmptyTestEnum::to_typescript_string(),
+        "type EmptyTestEnum = never;"
+    );
+    assert_eq!(
+        EmptyTestEnum::to_flow_string(),
+        "type EmptyTestEnum = empty;"
+    );
+    assert_eq!(
+        EmptyTestEnum::to_python_string(),
+        "class EmptyTestEnum(str, Enum):\n    pass\n"
+    );
+    assert_eq!(
+        EmptyTestEnum::to_elixir_string(),
+        "defmodule EmptyTestEnum do\n  @type t :: none()\nend"
+    );
+    assert_eq!(EmptyTestEnum::to_ocaml_string(), "type empty_test_enum = |");
+    assert_eq!(
+        EmptyTestEnum::to_fsharp_string(),
+        "type EmptyTestEnum = class end"
+    );
+    assert_eq!(EmptyTestEnum::to_go_string(), "type EmptyTestEnum string");
+    assert_eq!(EmptyTestEnum::to_swift_string(), "enum EmptyTestEnum {\n}");
+    assert_eq!(
+        EmptyTestEnum::to_proto_string(),
+        "enum EmptyTestEnum {\n    EMPTY_TEST_ENUM_UNSPECIFIED = 0;\n}"
+    );
+
+    assert_eq!(
+        SkippedTestEnum::to_typescript_string(),
+        "type SkippedTestEnum = never;"
+    );
+    assert_eq!(
+        SkippedTestEnum::to_python_string(),
+        "class SkippedTestEnum(str, Enum):\n    pass\n"
+    );
+
+    assert_eq!(
+        SkippedUntaggedTestEnum::to_typescript_string(),
+        "type SkippedUntaggedTestEnum = never;"
+    );
+    assert_eq!(
+        SkippedUntaggedTestEnum::to_python_string(),
+        "SkippedUntaggedTestEnum = NoReturn"
+    );
+    assert_eq!(
+        SkippedUntaggedTestEnum::to_elixir_string(),
+        "defmodule SkippedUntaggedTestEnum do\n  @type t :: none()\nend"
+    );
+}
+
+#[derive(StructToString)]
+struct PointerSizedTestStruct {
+    len: usize,
+    offset: isize,
+}
+
+#[test]
+fn pointer_sized_integers() {
+    assert!(PointerSizedTestStruct::to_typescript_string().contains("    len: number;\n"));
+    assert!(PointerSizedTestStruct::to_python_string().contains("    len: int\n"));
+    let go = PointerSizedTestStruct::to_go_string();
+    assert!(go.contains("    Len uint `json:\"len\"`\n"));
+    assert!(go.contains("    Offset int `json:\"offset\"`\n"));
+    assert!(PointerSizedTestStruct::to_java_string().contains("    public long len;\n"));
+    let csharp = PointerSizedTestStruct::to_csharp_string();
+    assert!(csharp.contains("    public nuint len;\n"));
+    assert!(csharp.contains("    public nint offset;\n"));
+    assert!(PointerSizedTestStruct::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct NonZeroTestStruct {
+    page_size: std::num::NonZeroU32,
+    retries: Option<std::num::NonZeroU32>,
+    ids: Vec<std::num::NonZero<u64>>,
+}
+
+#[test]
+fn nonzero_integers() {
+    assert_golden(
+        "NonZeroTestStruct/to_typescript_string.ts",
+        &NonZeroTestStruct::to_typescript_string(),
+    );
+    assert!(NonZeroTestStruct::to_go_string()
+        .contains("    Retries *uint32 `json:\"retries,omitempty\"` // nonzero\n"));
+    assert!(
+        NonZeroTestStruct::to_python_string().contains("    retries: Optional[int]  # nonzero\n")
+    );
+    assert!(NonZeroTestStruct::to_rust_string().contains("    page_size: std::num::NonZeroU32,\n"));
+    assert!(NonZeroTestStruct::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct ResultTestStruct {
+    outcome: Result<i32, String>,
+    retried: Option<Result<u32, NestedStruct>>,
+}
+
+#[test]
+fn results_as_tagged_unions() {
+    assert_golden(
+        "ResultTestStruct/to_typescript_string.ts",
+        &ResultTestStruct::to_typescript_string(),
+    );
+    assert_golden(
+        "ResultTestStruct/to_python_string.py",
+        &ResultTestStruct::to_python_string(),
+    );
+    assert!(ResultTestStruct::to_flow_string()
+        .contains("  retried: ?({| Ok: number |} | {| Err: NestedStruct |}),\n"));
+    assert!(ResultTestStruct::to_java_string().contains(
+        "    public Map<String, Object> outcome; // Result, either {\"Ok\": ...} or {\"Err\": ...}\n"
+    ));
+    assert_eq!(ResultTestStruct::referenced_types(), vec!["NestedStruct"]);
+}
+
+#[test]
+fn typescript_result_strategies() {
+    assert_eq!(
+        ResultTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .result(ResultStrategy::Tagged)
+            .build(),
+        ResultTestStruct::to_typescript_string()
+    );
+    assert_eq!(
+        ResultTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .result(ResultStrategy::Union)
+            .build(),
+        "interface ResultTestStruct {\n    outcome: number | string;\n    retried?: number | NestedStruct | null;\n}"
+    );
+    assert_eq!(
+        ResultTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .result(ResultStrategy::Unwrap)
+            .build(),
+        "interface ResultTestStruct {\n    outcome: number;\n    retried?: number | null;\n}"
+    );
+}
+
+#[derive(StructToString)]
+struct UnitTestStruct {
+    id: u32,
+    ack: (),
+    marker: std::marker::PhantomData<NestedStruct>,
+}
+
+#[test]
+fn unit_type_and_phantom_data() {
+    assert_golden(
+        "UnitTestStruct/to_typescript_string.ts",
+        &UnitTestStruct::to_typescript_string(),
+    );
+    assert!(UnitTestStruct::to_python_string().ends_with("    id: int\n    ack: None\n"));
+    assert!(UnitTestStruct::to_go_string()
+        .contains("    Ack *struct{} `json:\"ack\"` // unit type, always null\n"));
+    assert!(UnitTestStruct::to_java_string().contains("    public Void ack;\n"));
+    assert!(UnitTestStruct::to_csharp_string().contains("    public object ack;\n"));
+    assert!(UnitTestStruct::to_rust_string()
+        .contains("    marker: std::marker::PhantomData<NestedStruct>\n"));
+    assert!(UnitTestStruct::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct NestedTupleTestStruct {
+    cells: std::collections::HashMap<(i32, i32), String>,
+    pairs: Vec<(u8, bool)>,
+}
+
+#[test]
+fn go_note_after_tags_for_nested_tuples() {
+    let go = NestedTupleTestStruct::to_go_string();
+    assert!(go.contains(
+        "    Cells map[struct{}]string `json:\"cells\"` // CANNOT CONVERT THIS TO THE GO PROGRAMMING LANGUAGE. TUPLES ARE UNSUPPORTED BY GO: (int32, int32)\n"
+    ));
+    assert!(go.contains(
+        "    Pairs []struct{} `json:\"pairs\"` // CANNOT CONVERT THIS TO THE GO PROGRAMMING LANGUAGE. TUPLES ARE UNSUPPORTED BY GO: (uint8, bool)\n"
+    ));
+}
+
+#[derive(StructToString)]
+struct CsvTestStruct {
+    id: u64,
+    name: String,
+    score: Option<f64>,
+    active: bool,
+}
+
+#[test]
+fn to_csv_schema() {
+    let struct_string = CsvTestStruct::to_csv_schema_string().unwrap();
+
+    assert_golden("CsvTestStruct/to_csv_schema_string.csv", &struct_string);
+}
+
+#[test]
+fn to_sql_insert() {
+    let insert = CsvTestStruct::to_sql_insert_string();
+    assert_eq!(
+        insert,
+        "INSERT INTO csv_test_struct (id, name, score, active) VALUES (?, ?, ?, ?);"
+    );
+    assert_eq!(
+        insert.matches('?').count(),
+        CsvTestStruct::field_names().len()
+    );
+}
+
+#[test]
+fn to_java_with_optional() {
+    let java = CsvTestStruct::string_builder()
+        .language(Language::Java)
+        .java_optional(true)
+        .build();
+    assert!(java.contains("    public Optional<Double> score;\n"));
+    assert!(java.contains("    public BigInteger id;\n"));
+    assert!(TypeScriptOptionTestStruct::string_builder()
+        .language(Language::Java)
+        .java_optional(true)
+        .build()
+        .contains("    public Optional<Integer> score;\n"));
+    assert!(CsvTestStruct::string_builder()
+        .language(Language::Java)
+        .java_optional(false)
+        .build()
+        .contains("    public Double score;\n"));
+    assert!(ConstructorTestStruct::string_builder()
+        .language(Language::Java)
+        .java_optional(true)
+        .build()
+        .contains("    public List<String> tags;\n"));
+}
+
+#[test]
+fn to_csv_schema_rejects_nested_fields() {
+    assert_eq!(
+        ConstructorTestStruct::to_csv_schema_string(),
+        Err(String::from(
+            "field `tags` of type `Vec<String>` is not a flat CSV column"
+        ))
+    );
+}
+
+mod serde_bytes {
+    pub struct ByteBuf;
+}
+
+#[derive(StructToString)]
+struct ByteBufTestStruct {
+    payload: serde_bytes::ByteBuf,
+    #[serde(with = "serde_bytes")]
+    signature: Option<Vec<u8>>,
+    raw: Vec<u8>,
+}
+
+#[test]
+fn byte_buf_to_go() {
+    let struct_string = ByteBufTestStruct::to_go_string();
+
+    assert_golden("ByteBufTestStruct/to_go_string.go", &struct_string);
+}
+
+#[test]
+fn byte_buf_to_other_languages() {
+    assert!(ByteBufTestStruct::to_python_string().contains("    signature: Optional[List[int]]\n"));
+    assert!(ByteBufTestStruct::to_typescript_string().contains("    payload: number[];\n"));
+    assert!(ByteBufTestStruct::to_rust_string().contains("    signature: Option<Vec<u8>>,\n"));
+    assert!(ByteBufTestStruct::referenced_types().is_empty());
+    assert_eq!(
+        ByteBufTestStruct::to_csv_schema_string(),
+        Err(String::from(
+            "field `raw` of type `Vec<u8>` is not a flat CSV column"
+        ))
+    );
+}
+
+mod byte_buf {
+    use struct_to_string::StructToString;
+
+    #[derive(StructToString)]
+    pub struct Payload {
+        data: super::serde_bytes::ByteBuf,
+        signature: Option<super::serde_bytes::ByteBuf>,
+    }
+}
+
+mod byte_vec {
+    use struct_to_string::StructToString;
+
+    #[derive(StructToString)]
+    pub struct Payload {
+        data: Vec<u8>,
+        signature: Option<Vec<u8>>,
+    }
+}
+
+#[test]
+fn byte_buf_maps_like_a_byte_vec() {
+    for language in Language::ALL {
+        if language == Language::Rust {
+            continue;
+        }
+        assert_eq!(
+            byte_buf::Payload::to_string_in(language),
+            byte_vec::Payload::to_string_in(language)
+        );
+    }
+    assert_eq!(
+        byte_buf::Payload::to_json_schema_string(),
+        byte_vec::Payload::to_json_schema_string()
+    );
+    assert_eq!(
+        byte_buf::Payload::to_openapi_schema_string(),
+        byte_vec::Payload::to_openapi_schema_string()
+    );
+}
+
+#[test]
+fn to_java_with_stream_collection() {
+    let struct_string = Wrapper::<i32>::string_builder()
+        .language(Language::Java)
+        .java_collection(JavaCollection::Stream)
+        .build();
+
+    assert_golden(
+        "Wrapper/string_builder_java_collection_stream.java",
+        &struct_string,
+    );
+}
+
+#[test]
+fn to_java_with_collection() {
+    assert!(ConstructorTestStruct::string_builder()
+        .language(Language::Java)
+        .java_collection(JavaCollection::Iterable)
+        .build()
+        .contains("    public Iterable<String> tags;\n"));
+    assert_eq!(
+        ConstructorTestStruct::string_builder()
+            .language(Language::Java)
+            .java_collection(JavaCollection::List)
+            .build(),
+        ConstructorTestStruct::to_java_string()
+    );
+    assert!(ComprehensiveTestStruct::string_builder()
+        .language(Language::Java)
+        .java_collection(JavaCollection::Stream)
+        .build()
+        .contains("    public Stream<Integer> slice_field;\n"));
+    assert!(SmartPointerTestStruct::string_builder()
+        .language(Language::Java)
+        .java_collection(JavaCollection::Stream)
+        .build()
+        .contains("    public int[] values;\n"));
+}
+
+#[derive(StructToString)]
+struct CppTestStruct {
+    id: u64,
+    scores: std::collections::HashMap<String, u32>,
+    nested: std::collections::BTreeMap<String, Vec<Option<f64>>>,
+    tags: Vec<String>,
+}
+
+#[test]
+fn test_cpp_string() {
+    assert_golden(
+        "CppTestStruct/to_cpp_string.hpp",
+        &CppTestStruct::to_cpp_string(),
+    );
+}
+
+#[test]
+fn test_cpp_string_with_guard() {
+    assert_golden(
+        "ConstructorTestStruct/to_cpp_string_with_guard_user_h.hpp",
+        &ConstructorTestStruct::to_cpp_string_with_guard("USER_H"),
+    );
+}
+
+#[derive(StructToString)]
+struct IntegerWidthTestStruct {
+    delta: i32,
+    count: u32,
+    id: u64,
+    offset: Option<i64>,
+    samples: Vec<u64>,
+    ratio: f64,
+    name: String,
+}
+
+#[test]
+fn test_typescript_integer_widths() {
+    assert_golden(
+        "IntegerWidthTestStruct/string_builder_integer_widths.ts",
+        &IntegerWidthTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .integer_widths(true)
+            .build(),
+    );
+    assert_eq!(
+        IntegerWidthTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .integer_widths(false)
+            .build(),
+        IntegerWidthTestStruct::to_typescript_string()
+    );
+}
+
+#[test]
+fn test_typescript_readonly() {
+    assert_golden(
+        "ComprehensiveTestStruct/string_builder_readonly.ts",
+        &ComprehensiveTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .readonly(true)
+            .build(),
+    );
+    assert_eq!(
+        ComprehensiveTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .readonly(false)
+            .build(),
+        ComprehensiveTestStruct::to_typescript_string()
+    );
+}
+
+#[test]
+fn test_rust_string_with_derive() {
+    assert_golden(
+        "ConstructorTestStruct/to_rust_string_with_derive_true.rs",
+        &ConstructorTestStruct::to_rust_string_with_derive(true),
+    );
+    assert_eq!(
+        ConstructorTestStruct::to_rust_string_with_derive(false),
+        ConstructorTestStruct::to_rust_string()
+    );
+}
+
+#[derive(StructToString)]
+struct FlowTestStruct {
+    id: u32,
+    name: String,
+    nickname: Option<String>,
+    tags: Vec<String>,
+    scores: std::collections::HashMap<String, f64>,
+    nested: Option<NestedStruct>,
+}
+
+#[test]
+fn test_flow_string() {
+    assert_golden(
+        "FlowTestStruct/to_flow_string.js",
+        &FlowTestStruct::to_flow_string(),
+    );
+}
+
+#[test]
+fn test_elixir_string() {
+    assert_golden(
+        "FlowTestStruct/to_elixir_string.ex",
+        &FlowTestStruct::to_elixir_string(),
+    );
+    assert_eq!(
+        StatusTestEnum::to_elixir_string(),
+        "defmodule StatusTestEnum do\n  @type t :: :Active | :InProgress | :done\nend"
+    );
+    assert_eq!(
+        UserId::to_elixir_string(),
+        "defmodule UserId do\n  @type t :: integer()\nend"
+    );
+    assert_eq!(
+        UntaggedTestEnum::to_elixir_string(),
+        "defmodule UntaggedTestEnum do\n  @type t :: nil | float() | {integer(), integer()} | %{w: float(), height: float()} | NestedStruct.t()\nend"
+    );
+    assert_eq!(
+        IndentTestStruct::to_elixir_string(),
+        "defmodule IndentTestStruct do\n  defstruct [:id, :tags]\n\n  @type t :: %__MODULE__{\n    id: integer(),\n    tags: [String.t()]\n  }\nend"
+    );
+}
+
+#[test]
+fn test_ocaml_string() {
+    assert_golden(
+        "FlowTestStruct/to_ocaml_string.ml",
+        &FlowTestStruct::to_ocaml_string(),
+    );
+    assert_eq!(
+        StatusTestEnum::to_ocaml_string(),
+        "type status_test_enum = Active | InProgress | Done"
+    );
+    assert_eq!(UserId::to_ocaml_string(), "type user_id = int64");
+    assert!(ResultTestStruct::to_ocaml_string()
+        .contains("  retried : (int, nested_struct) result option;\n"));
+}
+
+#[test]
+fn java_optional_custom_type_keeps_its_name() {
+    let java = FlowTestStruct::to_java_string();
+    assert!(java.contains("    public NestedStruct nested;\n"));
+    assert!(!java.contains("Object"));
+    assert!(FlowTestStruct::string_builder()
+        .language(Language::Java)
+        .java_optional(true)
+        .build()
+        .contains("    public Optional<NestedStruct> nested;\n"));
+}
+
+#[test]
+fn test_fsharp_string() {
+    assert_golden(
+        "FlowTestStruct/to_fsharp_string.fs",
+        &FlowTestStruct::to_fsharp_string(),
+    );
+    assert_eq!(
+        StatusTestEnum::to_fsharp_string(),
+        "type StatusTestEnum =\n    | Active\n    | InProgress\n    | Done"
+    );
+    assert_eq!(UserId::to_fsharp_string(), "type UserId = uint64");
+    assert!(ResultTestStruct::to_fsharp_string()
+        .contains("    Retried: Result<int64, NestedStruct> option\n"));
+}
+
+pub struct CountryCode(String);
+
+#[derive(StructToString)]
+struct PatternTestStruct {
+    #[struct_to_string(pattern = "^[A-Z]{2}$")]
+    country: CountryCode,
+    #[struct_to_string(pattern = "^[A-Z]{3}$")]
+    currency: Option<CountryCode>,
+}
+
+#[test]
+fn test_pattern_attribute() {
+    assert_golden(
+        "PatternTestStruct/to_openapi_schema_string.yaml",
+        &PatternTestStruct::to_openapi_schema_string(),
+    );
+    assert!(PatternTestStruct::to_typescript_string().contains("    country: string;\n"));
+    assert!(PatternTestStruct::to_typescript_string().contains("    currency?: string | null;\n"));
+    assert!(PatternTestStruct::to_go_string().contains("    Country string `json:\"country\"`"));
+    assert!(PatternTestStruct::to_java_string().contains("    public String country;\n"));
+    assert!(PatternTestStruct::to_rust_string().contains("    country: CountryCode,\n"));
+}
+
+#[derive(StructToString)]
+struct FlattenBaseStruct {
+    id: u64,
+    created_by: String,
+}
+
+pub struct UnregisteredBase;
+
+#[derive(StructToString)]
+struct FlattenTestStruct {
+    name: String,
+    #[serde(flatten)]
+    base: FlattenBaseStruct,
+    #[serde(flatten)]
+    extra: UnregisteredBase,
+    active: bool,
+}
+
+#[test]
+fn test_flatten_inlines_registered_fields() {
+    assert!(FlattenTestStruct::to_python_string()
+        .ends_with("    name: str\n    id: int\n    created_by: str\n    extra: UnregisteredBase\n    active: bool\n"));
+    assert!(FlattenTestStruct::string_builder()
+        .language(Language::Kotlin)
+        .mutable(true)
+        .build()
+        .contains("    var id: ULong,\n    var created_by: String,\n"));
+    assert!(FlattenTestStruct::to_rust_string().contains("    base: FlattenBaseStruct,\n"));
+}
+
+#[test]
+fn test_flatten_extends_typescript_interface() {
+    assert_golden(
+        "FlattenTestStruct/to_typescript_string.ts",
+        &FlattenTestStruct::to_typescript_string(),
+    );
+    assert!(FlattenTestStruct::to_typescript_type_alias_string(false)
+        .starts_with("type FlattenTestStruct = FlattenBaseStruct & UnregisteredBase & {\n"));
+    assert!(FlattenTestStruct::to_zod_string().contains("  created_by: z.string(),\n"));
+}
+
+#[derive(StructToString)]
+struct PydanticTestStruct {
+    id: u64,
+    name: String,
+    nickname: Option<String>,
+    tags: Vec<String>,
+    scores: std::collections::HashMap<String, f64>,
+}
+
+#[test]
+fn test_pydantic_string() {
+    assert_golden(
+        "PydanticTestStruct/to_pydantic_string.py",
+        &PydanticTestStruct::to_pydantic_string(),
+    );
+    assert!(!PydanticTestStruct::to_pydantic_string().contains("@dataclass"));
+}
+
+#[derive(StructToString)]
+struct HclTestStruct {
+    instance_count: u32,
+    region: String,
+    zones: Vec<u8>,
+    ports: Vec<u16>,
+    tags: std::collections::HashMap<String, String>,
+    description: Option<String>,
+}
+
+#[test]
+fn test_hcl_string() {
+    assert_golden(
+        "HclTestStruct/to_hcl_string.tf",
+        &HclTestStruct::to_hcl_string(),
+    );
+}
+
+#[derive(StructToString)]
+struct ModernPythonTestStruct {
+    count: i32,
+    limit: Option<i32>,
+    tags: Vec<String>,
+    scores: std::collections::HashMap<String, i64>,
+    pair: (i32, String),
+}
+
+#[test]
+fn test_python_string_modern() {
+    assert_golden(
+        "ModernPythonTestStruct/to_python_string_modern.py",
+        &ModernPythonTestStruct::to_python_string_modern(),
+    );
+    assert!(ModernPythonTestStruct::to_python_string().contains("    limit: Optional[int]\n"));
+}
+
+#[derive(StructToString)]
+pub struct UserId(pub u64);
+
+#[test]
+fn test_newtype_python_string() {
+    assert_golden("UserId/to_python_string.py", &UserId::to_python_string());
+    assert_eq!(UserId::to_rust_string(), "pub struct UserId(pub u64);");
+    assert_eq!(UserId::to_typescript_string(), "type UserId = number;");
+    assert_eq!(UserId::to_go_string(), "type UserId uint64");
+}
+
+#[derive(StructToString)]
+struct ErrorTestStruct {
+    last_error: Option<Box<dyn std::error::Error>>,
+    cause: Box<dyn std::error::Error + Send + Sync>,
+}
+
+#[test]
+fn test_dyn_error_mapping() {
+    assert_golden(
+        "ErrorTestStruct/to_typescript_string.ts",
+        &ErrorTestStruct::to_typescript_string(),
+    );
+    assert!(ErrorTestStruct::to_python_string()
+        .contains("    last_error: Optional[Exception]\n    cause: Exception\n"));
+    assert!(ErrorTestStruct::to_go_string()
+        .contains("    LastError error `json:\"last_error,omitempty\"`"));
+    assert!(ErrorTestStruct::to_java_string().contains("    public Exception cause;\n"));
+    assert!(ErrorTestStruct::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct BuilderTestStruct {
+    user_name: String,
+    login_count: Option<u32>,
+}
+
+#[test]
+fn test_string_builder() {
+    let expected_go_string =
+        "type BuilderTestStruct struct {\n\tUserName string `json:\"userName\"`\n\tLoginCount *uint32 `json:\"loginCount,omitempty\"`\n}";
+
+    let generated_go_string = BuilderTestStruct::string_builder()
+        .indent("\t")
+        .language(Language::Go)
+        .rename_all(Case::Camel)
+        .build();
+
+    println!("--- GO BUILDER CONVERSION --- ");
+    println!("--- WHAT WAS GENERATED --- ");
+    println!("{}", generated_go_string);
+    println!("--- WHAT WAS EXPECTED --- ");
+    println!("{}", expected_go_string);
+
+    assert_eq!(generated_go_string, expected_go_string);
+    assert_eq!(
+        BuilderTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .export(true)
+            .build(),
+        format!("export {}", BuilderTestStruct::to_typescript_string())
+    );
+    assert_eq!(
+        BuilderTestStruct::field_names(),
+        vec!["user_name", "login_count"]
+    );
+}
+
+#[derive(StructToString)]
+struct CombinedOptionsTestStruct {
+    login_count: u64,
+    tags: Vec<String>,
+    nickname: Option<String>,
+}
+
+#[test]
+fn builder_options_combine() {
+    let typescript = CombinedOptionsTestStruct::string_builder()
+        .language(Language::TypeScript)
+        .readonly(true)
+        .integer_widths(true)
+        .rust_names(true)
+        .rename_all(Case::Camel)
+        .indent("  ")
+        .build();
+    assert_eq!(
+        typescript,
+        "interface CombinedOptionsTestStruct {\n  readonly loginCount: number; // rust: login_count, u64\n  readonly tags: string[];\n  readonly nickname?: string | null;\n}"
+    );
+
+    let java = CombinedOptionsTestStruct::string_builder()
+        .language(Language::Java)
+        .java_collection(JavaCollection::Stream)
+        .java_optional(true)
+        .build();
+    assert!(
+        java.contains("    public Stream<String> tags;\n    public Optional<String> nickname;\n")
+    );
+
+    let kotlin = CombinedOptionsTestStruct::string_builder()
+        .language(Language::Kotlin)
+        .mutable(true)
+        .rename_all(Case::Camel)
+        .build();
+    assert!(kotlin.contains("    var loginCount: ULong,\n"));
+}
+
+#[derive(StructToString)]
+struct RenameScopeTestStruct {
+    character: char,
+    login_count: u32,
+}
+
+#[test]
+fn builder_rename_all_only_renames_field_names() {
+    let typescript = RenameScopeTestStruct::string_builder()
+        .language(Language::TypeScript)
+        .rename_all(Case::Pascal)
+        .build();
+    assert_eq!(
+        typescript,
+        "interface RenameScopeTestStruct {\n    Character: string; // single character\n    LoginCount: number;\n}"
+    );
+    let rust = RenameScopeTestStruct::string_builder()
+        .language(Language::Rust)
+        .rename_all(Case::Pascal)
+        .build();
+    assert_eq!(rust, RenameScopeTestStruct::to_rust_string());
+
+    // The fields inlined from a flattened type are renamed and reindented along with the rest.
+    let python = FlattenTestStruct::string_builder()
+        .language(Language::Python)
+        .rename_all(Case::Camel)
+        .indent("\t")
+        .build();
+    assert!(python.ends_with(
+        "\tname: str\n\tid: int\n\tcreatedBy: str\n\textra: UnregisteredBase\n\tactive: bool\n"
+    ));
+}
+
+#[test]
+fn schema_hash_comment() {
+    let hash = BuilderTestStruct::schema_hash();
+    assert_eq!(hash, BuilderTestStruct::schema_hash());
+    // Pinned, so a change to the hashing that would break stored hashes is noticed.
+    assert_eq!(hash, 0xa72ac369cc663545);
+    assert_ne!(hash, VisibilityTestStruct::schema_hash());
+
+    let typescript = BuilderTestStruct::string_builder()
+        .language(Language::TypeScript)
+        .schema_hash(true)
+        .build();
+    assert_eq!(
+        typescript,
+        format!(
+            "// schema hash: {:016x}\n{}",
+            hash,
+            BuilderTestStruct::to_typescript_string()
+        )
+    );
+    assert!(BuilderTestStruct::string_builder()
+        .language(Language::Python)
+        .schema_hash(true)
+        .build()
+        .starts_with(&format!("# schema hash: {:016x}\n", hash)));
+    assert_ne!(
+        StatusTestEnum::schema_hash(),
+        PriorityTestEnum::schema_hash()
+    );
+}
+
+#[derive(StructToString)]
+struct RenamedHashTestStruct {
+    #[serde(rename = "userName")]
+    user_name: String,
+    login_count: Option<u32>,
+}
+
+#[derive(StructToString)]
+struct SkippedHashTestStruct {
+    user_name: String,
+    login_count: Option<u32>,
+    #[serde(skip)]
+    cache: Vec<u8>,
+}
+
+#[test]
+fn schema_hash_follows_the_serialized_shape() {
+    // The same fields hash alike once skipped ones are left out, but a rename changes the hash.
+    assert_eq!(
+        SkippedHashTestStruct::schema_hash(),
+        BuilderTestStruct::schema_hash()
+    );
+    assert_ne!(
+        RenamedHashTestStruct::schema_hash(),
+        BuilderTestStruct::schema_hash()
+    );
+}
+
+struct ContactEmail(String);
+
+struct VerifiedEmail(String);
+
+#[derive(StructToString)]
+struct JsonSchemaTestStruct {
+    id: uuid::Uuid,
+    created_at: chrono::DateTime<chrono::Utc>,
+    #[struct_to_string(format = "email")]
+    contact: ContactEmail,
+    backup_contact: VerifiedEmail,
+    #[struct_to_string(pattern = "^[A-Z]{2}$")]
+    country: String,
+    scores: Vec<f64>,
+    note: Option<String>,
+}
+
+#[cfg(all(feature = "uuid", feature = "chrono"))]
+#[test]
+fn test_json_schema_string() {
+    assert_golden(
+        "JsonSchemaTestStruct/to_json_schema_string.json",
+        &JsonSchemaTestStruct::to_json_schema_string(),
+    );
+    assert!(JsonSchemaTestStruct::to_json_schema_string().contains(r#""format": "uuid""#));
+    assert!(JsonSchemaTestStruct::to_openapi_schema_string()
+        .contains("    contact:\n      type: string\n      format: email\n"));
+    assert!(JsonSchemaTestStruct::to_typescript_string().contains("    contact: string;\n"));
+}
+
+#[derive(StructToString)]
+struct NoneDefaultTestStruct {
+    nickname: Option<String>,
+    age: Option<u32>,
+    name: String,
+}
+
+#[test]
+fn test_python_string_with_none_defaults() {
+    assert_golden(
+        "NoneDefaultTestStruct/to_python_string_with_none_defaults_true.py",
+        &NoneDefaultTestStruct::to_python_string_with_none_defaults(true),
+    );
+    assert_eq!(
+        NoneDefaultTestStruct::to_python_string_with_none_defaults(false),
+        NoneDefaultTestStruct::to_python_string()
+    );
+}
+
+#[derive(StructToString)]
+struct NestedGenericsTestStruct {
+    nested: Vec<std::collections::HashMap<String, Vec<i32>>>,
+    deep: std::collections::HashMap<String, std::collections::HashMap<String, Vec<Option<f64>>>>,
+}
+
+#[test]
+fn test_nested_generics() {
+    assert_golden(
+        "NestedGenericsTestStruct/to_typescript_string.ts",
+        &NestedGenericsTestStruct::to_typescript_string(),
+    );
+    assert!(NestedGenericsTestStruct::to_python_string().contains(
+        "    nested: List[Dict[str, List[int]]]\n    deep: Dict[str, Dict[str, List[Optional[float]]]]\n"
+    ));
+    assert!(NestedGenericsTestStruct::to_python_string_modern()
+        .contains("    deep: dict[str, dict[str, list[float | None]]]\n"));
+    assert!(NestedGenericsTestStruct::to_go_string()
+        .contains("    Nested []map[string][]int32 `json:\"nested\"`\n    Deep map[string]map[string][]*float64 `json:\"deep\"`\n"));
+    assert!(NestedGenericsTestStruct::to_java_string()
+        .contains("    public List<Map<String, List<Integer>>> nested;\n"));
+    assert!(NestedGenericsTestStruct::to_csharp_string()
+        .contains("    public Dictionary<string, Dictionary<string, List<double?>>> deep;\n"));
+    assert!(NestedGenericsTestStruct::to_kotlin_string()
+        .contains("    val nested: List<Map<String, List<Int>>>,\n"));
+    assert!(NestedGenericsTestStruct::to_swift_string()
+        .contains("    let deep: [String: [String: [Double?]]]\n"));
+    assert!(NestedGenericsTestStruct::to_cpp_string()
+        .contains("    std::vector<std::map<std::string, std::vector<int32_t>>> nested;\n"));
+    assert!(NestedGenericsTestStruct::to_flow_string()
+        .contains("  deep: { [key: string]: { [key: string]: Array<?number> } },\n"));
+}
+
+mod heapless {
+    pub struct Vec<T, const N: usize>([T; N]);
+}
+
+mod tinyvec {
+    pub struct TinyVec<A>(A);
+}
+
+#[derive(StructToString)]
+struct FixedCapacityTestStruct {
+    readings: heapless::Vec<f32, 16>,
+    labels: tinyvec::TinyVec<[String; 4]>,
+    nested: Option<heapless::Vec<tinyvec::TinyVec<[u32; 2]>, 8>>,
+}
+
+#[test]
+fn fixed_capacity_vecs_to_typescript() {
+    assert_golden(
+        "FixedCapacityTestStruct/to_typescript_string.ts",
+        &FixedCapacityTestStruct::to_typescript_string(),
+    );
+}
+
+#[test]
+fn fixed_capacity_vecs_to_other_languages() {
+    assert!(FixedCapacityTestStruct::to_python_string().contains(
+        "    readings: List[float]\n    labels: List[str]\n    nested: Optional[List[List[int]]]\n"
+    ));
+    assert!(
+        FixedCapacityTestStruct::to_go_string().contains("    Labels []string `json:\"labels\"`\n")
+    );
+    assert!(FixedCapacityTestStruct::to_java_string().contains("    public List<String> labels;\n"));
+    assert!(
+        FixedCapacityTestStruct::to_csharp_string().contains("    public List<float> readings;\n")
+    );
+    assert!(FixedCapacityTestStruct::to_kotlin_string().contains("    val labels: List<String>,\n"));
+    assert!(FixedCapacityTestStruct::to_swift_string().contains("    let labels: [String]\n"));
+    assert!(
+        FixedCapacityTestStruct::to_cpp_string().contains("    std::vector<std::string> labels;\n")
+    );
+    assert!(FixedCapacityTestStruct::to_flow_string().contains("  labels: Array<string>,\n"));
+    assert!(FixedCapacityTestStruct::referenced_types().is_empty());
+}
+
+#[derive(StructToString)]
+struct StringLikeTestStruct<'a> {
+    name: std::borrow::Cow<'a, str>,
+    label: &'a str,
+    shared: std::sync::Arc<str>,
+    boxed: Box<str>,
+    aliases: Vec<std::borrow::Cow<'a, str>>,
+    scores: std::borrow::Cow<'a, [u32]>,
+}
+
+#[test]
+fn string_like_types_map_to_strings() {
+    assert_golden(
+        "StringLikeTestStruct/to_typescript_string.ts",
+        &StringLikeTestStruct::to_typescript_string(),
+    );
+    assert!(StringLikeTestStruct::to_python_string()
+        .contains("    name: str\n    label: str\n    shared: str\n    boxed: str\n"));
+    assert!(
+        StringLikeTestStruct::to_go_string().contains("    Aliases []string `json:\"aliases\"`\n")
+    );
+    assert!(StringLikeTestStruct::to_java_string().contains("    public String label;\n"));
+    assert!(StringLikeTestStruct::to_kotlin_string().contains("    val shared: String,\n"));
+    assert!(StringLikeTestStruct::referenced_types().is_empty());
+}
+
+#[test]
+fn lifetime_arguments_are_skipped() {
+    assert!(StringLikeTestStruct::to_typescript_string()
+        .contains("    name: string;\n    label: string;\n"));
+    assert!(StringLikeTestStruct::to_rust_string()
+        .contains("    name: std::borrow::Cow<'a,str>,\n    label: &'a str,\n"));
+    assert!(ErrorTestStruct::to_rust_string()
+        .contains("    cause: Box<dyn std::error::Error+Send+Sync>\n"));
+}
+
+mod arrayvec {
+    pub struct ArrayString<const CAP: usize>([u8; CAP]);
+}
+
+#[derive(StructToString)]
+struct ArrayStringTestStruct {
+    code: arrayvec::ArrayString<16>,
+    label: Option<arrayvec::ArrayString<64>>,
+}
+
+#[test]
+fn array_string_maps_to_string() {
+    assert!(ArrayStringTestStruct::to_typescript_string()
+        .contains("    code: string;\n    label?: string | null;\n"));
+    assert!(ArrayStringTestStruct::to_python_string().contains("    code: str\n"));
+    assert!(ArrayStringTestStruct::to_go_string().contains("    Code string `json:\"code\"`\n"));
+    assert!(ArrayStringTestStruct::to_java_string().contains("    public String code;\n"));
+    assert!(ArrayStringTestStruct::referenced_types().is_empty());
+
+    let schema = ArrayStringTestStruct::to_json_schema_string();
+    assert!(schema.contains(
+        r#""type": "string",
+      "maxLength": 16"#
+    ));
+    assert!(schema.contains(r#""maxLength": 64"#));
+}
+
+mod models {
+    pub struct Address;
+}
+
+#[derive(StructToString)]
+struct ShortNameTestStruct {
+    home: crate::models::Address,
+    previous: Vec<models::Address>,
+    lookup: std::collections::HashMap<String, Option<crate::models::Address>>,
+}
+
+#[test]
+fn rust_short_names() {
+    assert_golden(
+        "ShortNameTestStruct/to_rust_string_with_short_names_true.rs",
+        &ShortNameTestStruct::to_rust_string_with_short_names(true),
+    );
+    assert_eq!(
+        ShortNameTestStruct::to_rust_string_with_short_names(false),
+        ShortNameTestStruct::to_rust_string()
+    );
+    assert!(ShortNameTestStruct::to_rust_string().contains("    home: crate::models::Address,\n"));
+    assert!(ShortNameTestStruct::to_typescript_string().contains("    home: Address;\n"));
+}
+
+struct Point(f64, f64);
+
+#[derive(StructToString)]
+struct PolylineTestStruct {
+    points: Vec<Point>,
+    labels: Option<Vec<TupleStruct>>,
+}
+
+#[test]
+fn vec_of_tuple_structs_refers_to_the_named_type() {
+    assert_golden(
+        "PolylineTestStruct/to_typescript_string.ts",
+        &PolylineTestStruct::to_typescript_string(),
+    );
+    assert!(PolylineTestStruct::to_python_string().contains("    points: List[Point]\n"));
+    assert_eq!(
+        PolylineTestStruct::referenced_types(),
+        vec!["Point", "TupleStruct"]
+    );
+}
+
+#[derive(StructToString)]
+struct GoJsonTagTestStruct {
+    user_id: u64,
+    display_name: Option<String>,
+    avatar_url: String,
+}
+
+#[test]
+fn go_fields_are_exported_with_json_tags() {
+    let go = GoJsonTagTestStruct::to_go_string();
+    assert!(go.contains("    UserID uint64 `json:\"user_id\"`\n"));
+    assert!(go.contains("    DisplayName *string `json:\"display_name,omitempty\"`\n"));
+    assert!(go.contains("    AvatarURL string `json:\"avatar_url\"`\n"));
+}
+
+#[derive(StructToString)]
+#[serde(untagged)]
+enum UntaggedTestEnum {
+    Empty,
+    Radius(f64),
+    Point(i32, i32),
+    Rect {
+        w: f64,
+        #[serde(rename = "height")]
+        h: f64,
+    },
+    Nested(NestedStruct),
+}
+
+#[test]
+fn untagged_enum_to_plain_union() {
+    assert_golden(
+        "UntaggedTestEnum/to_typescript_string.ts",
+        &UntaggedTestEnum::to_typescript_string(),
+    );
+    assert_golden(
+        "UntaggedTestEnum/to_rust_string.rs",
+        &UntaggedTestEnum::to_rust_string(),
+    );
+    assert_eq!(
+        UntaggedTestEnum::to_python_string(),
+        "UntaggedTestEnum = Union[None, float, Tuple[int, int], Dict[str, Any], NestedStruct]"
+    );
+    assert_eq!(
+        UntaggedTestEnum::to_flow_string(),
+        "type UntaggedTestEnum = null | number | [number, number] | {| w: number, height: number |} | NestedStruct;"
+    );
+    assert_eq!(
+        UntaggedTestEnum::to_go_string(),
+        "type UntaggedTestEnum any"
+    );
+    assert_eq!(UntaggedTestEnum::referenced_types(), vec!["NestedStruct"]);
+}
+
+#[derive(StructToString)]
+#[serde(rename_all = "camelCase")]
+struct RenameAllTestStruct {
+    user_id: u64,
+    #[serde(rename = "mail")]
+    email_address: String,
+    last_login: Option<String>,
+}
+
+#[derive(StructToString)]
+#[serde(rename_all = "kebab-case")]
+enum RenameAllTestEnum {
+    InProgress,
+    Done,
+}
+
+#[test]
+fn serde_rename_all_to_go_tags() {
+    assert_golden(
+        "RenameAllTestStruct/to_go_string.go",
+        &RenameAllTestStruct::to_go_string(),
+    );
+    assert!(
+        RenameAllTestStruct::to_typescript_string().contains("    lastLogin?: string | null;\n")
+    );
+    assert!(RenameAllTestStruct::to_rust_string().contains("    user_id: u64,\n"));
+    assert_eq!(
+        RenameAllTestEnum::to_typescript_string(),
+        r#"type RenameAllTestEnum = "in-progress" | "done";"#
+    );
+}
+
+#[test]
+fn typescript_with_rust_names() {
+    assert_golden(
+        "RenameAllTestStruct/string_builder_rust_names.ts",
+        &RenameAllTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .rust_names(true)
+            .build(),
+    );
+    assert_eq!(
+        RenameAllTestStruct::string_builder()
+            .language(Language::TypeScript)
+            .rust_names(false)
+            .build(),
+        RenameAllTestStruct::to_typescript_string()
+    );
+}
+
+#[test]
+fn builder_rename_all_over_serde_rename_all() {
+    let python = RenameAllTestStruct::string_builder()
+        .language(Language::Python)
+        .rename_all(Case::Snake)
+        .build();
+    assert!(python.ends_with("    user_id: int\n    mail: str\n    last_login: Optional[str]\n"));
+    let java = RenameAllTestStruct::string_builder()
+        .language(Language::Java)
+        .rename_all(Case::ScreamingSnake)
+        .build();
+    assert!(java.contains("    public String LAST_LOGIN;\n"));
+
+    assert_eq!(Case::Camel.apply("UserId"), "userId");
+    assert_eq!(Case::Snake.apply("in-progress"), "in_progress");
+    assert_eq!(Case::Pascal.apply("HTTPServer_v2"), "HttpServerV2");
+}
+
+/// A user account.
+#[derive(StructToString)]
+// The `#[derive]` naming `StructToString` is consumed before the macro sees the struct.
+#[derive(Debug, Clone)]
+#[struct_to_string(pub_only)]
+#[allow(non_snake_case)]
+struct AttrsTestStruct {
+    user_id: u32,
+}
+
+#[test]
+fn to_rust_string_with_attrs() {
+    assert_eq!(
+        AttrsTestStruct::to_rust_string_with_attrs(),
+        "/// A user account.\n#[derive(Debug, Clone)]\n#[struct_to_string(pub_only)]\n#[allow(non_snake_case)]\nstruct AttrsTestStruct {\n    user_id: u32\n}"
+    );
+}
+
+#[derive(StructToString)]
+#[allow(dead_code)]
+struct LifetimeAttrsTestStruct<'a, T: Clone>
+where
+    T: std::fmt::Debug,
+{
+    /// The display name.
+    #[serde(rename = "n")]
+    name: &'a str,
+    value: T,
+}
+
+#[test]
+fn to_rust_string_with_attrs_keeps_generics_and_field_attrs() {
+    assert_eq!(
+        LifetimeAttrsTestStruct::<u8>::to_rust_string_with_attrs(),
+        "#[allow(dead_code)]\nstruct LifetimeAttrsTestStruct<'a, T: Clone> where T: std::fmt::Debug {\n    /// The display name.\n    #[serde(rename = \"n\")]\n    name: &'a str,\n    value: T\n}"
+    );
+}
+
+#[derive(StructToString)]
+struct RetryConfig {
+    attempts: u32,
+    backoff: Option<f64>,
+}
+
+#[derive(StructToString)]
+struct DatabaseConfig {
+    url: String,
+    pool_size: u32,
+    retry: RetryConfig,
+}
+
+#[derive(StructToString)]
+struct AppConfig {
+    name: String,
+    debug: bool,
+    level: PriorityTestEnum,
+    database: DatabaseConfig,
+    cache: Option<Box<DatabaseConfig>>,
+    external: ExternalConfig,
+}
+
+struct ExternalConfig;
+
+#[test]
+fn to_toml_example() {
+    assert_golden(
+        "AppConfig/to_toml_example_string.toml",
+        &AppConfig::to_toml_example_string(),
+    );
 }
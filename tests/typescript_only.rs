@@ -0,0 +1,58 @@
+// Run with `cargo test --no-default-features --features typescript`.
+#![cfg(all(
+    feature = "typescript",
+    not(any(
+        feature = "rust",
+        feature = "python",
+        feature = "go",
+        feature = "java",
+        feature = "csharp",
+        feature = "kotlin",
+        feature = "swift",
+        feature = "cpp",
+        feature = "flow",
+        feature = "elixir",
+        feature = "ocaml",
+        feature = "fsharp"
+    ))
+))]
+#![allow(dead_code)]
+
+extern crate struct_to_string;
+use struct_to_string::{Language, StructToString};
+
+#[derive(StructToString)]
+struct TypeScriptOnlyTestStruct {
+    id: u32,
+    name: String,
+}
+
+#[test]
+fn only_typescript_is_generated() {
+    let expected_ts_string = r#"interface TypeScriptOnlyTestStruct {
+    id: number;
+    name: string;
+}"#;
+
+    println!("--- TYPESCRIPT ONLY CONVERSION --- ");
+    println!("--- WHAT WAS GENERATED --- ");
+    println!("{}", TypeScriptOnlyTestStruct::to_typescript_string());
+    println!("--- WHAT WAS EXPECTED --- ");
+    println!("{}", expected_ts_string);
+
+    assert_eq!(
+        TypeScriptOnlyTestStruct::to_typescript_string(),
+        expected_ts_string
+    );
+    assert_eq!(Language::ALL, [Language::TypeScript]);
+    assert_eq!(
+        TypeScriptOnlyTestStruct::to_string_in(Language::TypeScript),
+        expected_ts_string
+    );
+    assert_eq!(
+        TypeScriptOnlyTestStruct::to_all_strings()
+            .keys()
+            .collect::<Vec<_>>(),
+        vec![&"typescript"]
+    );
+}
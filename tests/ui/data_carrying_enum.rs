@@ -0,0 +1,9 @@
+use struct_to_string::StructToString;
+
+#[derive(StructToString)]
+enum Shape {
+    Point,
+    Circle(f64),
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use struct_to_string::StructToString;
+
+#[derive(StructToString)]
+#[struct_to_string(display = "{id}-{nmae}")]
+struct Slug {
+    id: u64,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use struct_to_string::StructToString;
+
+#[derive(StructToString)]
+struct Point(f64, f64);
+
+fn main() {}
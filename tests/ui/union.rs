@@ -0,0 +1,9 @@
+use struct_to_string::StructToString;
+
+#[derive(StructToString)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}